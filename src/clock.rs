@@ -0,0 +1,143 @@
+use serde::{Serialize, Deserialize};
+use std::{
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH}
+};
+
+const SECONDS_PER_MINUTE: u64 = 60;
+const SECONDS_PER_HOUR: u64 = 60 * SECONDS_PER_MINUTE;
+const SECONDS_PER_DAY: u64 = 24 * SECONDS_PER_HOUR;
+
+const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun",
+    "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"
+];
+
+pub const fn minutes(amount: u64) -> Duration {
+    Duration::from_secs(amount * SECONDS_PER_MINUTE)
+}
+
+pub const fn hours(amount: u64) -> Duration {
+    Duration::from_secs(amount * SECONDS_PER_HOUR)
+}
+
+/// Formats a duration as `1h 05m`
+pub fn format_duration(duration: Duration) -> String {
+    let minutes = duration.as_secs() / SECONDS_PER_MINUTE;
+    format!("{}h {:02}m", minutes / 60, minutes % 60)
+}
+
+/// The phase of the day, which other systems key off of
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayPhase {
+    Dawn,
+    Day,
+    Dusk,
+    Night
+}
+
+impl DayPhase {
+    pub fn from_hour(hour: u64) -> Self {
+        match hour {
+            5..=6 => DayPhase::Dawn,
+            7..=18 => DayPhase::Day,
+            19..=20 => DayPhase::Dusk,
+            _ => DayPhase::Night
+        }
+    }
+}
+
+impl fmt::Display for DayPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let phase = match self {
+            DayPhase::Dawn => "Dawn",
+            DayPhase::Day => "Day",
+            DayPhase::Dusk => "Dusk",
+            DayPhase::Night => "Night"
+        };
+
+        write!(f, "{phase}")
+    }
+}
+
+fn seconds_since_epoch(time: &SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .expect("Game time is before the UNIX epoch")
+        .as_secs()
+}
+
+/// The hour of the day (0-23) of a given time
+pub fn hour_of_day(time: &SystemTime) -> u64 {
+    seconds_since_epoch(time) % SECONDS_PER_DAY / SECONDS_PER_HOUR
+}
+
+/// Formats a time as `Tue Jan 01 1985 05:00`
+pub fn format_time(time: &SystemTime) -> String {
+    let seconds = seconds_since_epoch(time);
+    let days = seconds / SECONDS_PER_DAY;
+    let (year, month, day) = civil_from_days(days);
+
+    format!(
+        "{} {} {:02} {} {:02}:{:02}",
+        WEEKDAYS[(days % 7) as usize],
+        MONTHS[month as usize - 1],
+        day,
+        year,
+        seconds % SECONDS_PER_DAY / SECONDS_PER_HOUR,
+        seconds % SECONDS_PER_HOUR / SECONDS_PER_MINUTE
+    )
+}
+
+/// Converts days since the UNIX epoch into a (year, month, day) date.
+/// See http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::GAME_START_EPOCH;
+
+    fn time(seconds: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(seconds)
+    }
+
+    #[test]
+    fn game_starts_on_new_years_morning_1985() {
+        assert_eq!(format_time(&time(GAME_START_EPOCH)), "Tue Jan 01 1985 05:00");
+        assert_eq!(hour_of_day(&time(GAME_START_EPOCH)), 5);
+    }
+
+    #[test]
+    fn leap_day() {
+        // 2000 is a leap year despite being divisible by 100
+        assert_eq!(format_time(&time(951_827_400)), "Tue Feb 29 2000 12:30");
+        assert_eq!(format_time(&time(951_827_400 + SECONDS_PER_DAY)), "Wed Mar 01 2000 12:30");
+    }
+
+    #[test]
+    fn year_rolls_over() {
+        let new_year = GAME_START_EPOCH + 31_518_000;
+
+        assert_eq!(format_time(&time(new_year - SECONDS_PER_MINUTE)), "Tue Dec 31 1985 23:59");
+        assert_eq!(format_time(&time(new_year)), "Wed Jan 01 1986 00:00");
+    }
+
+    #[test]
+    fn durations_are_formatted_in_hours_and_minutes() {
+        assert_eq!(format_duration(hours(1) + minutes(5)), "1h 05m");
+        assert_eq!(format_duration(minutes(45)), "0h 45m");
+    }
+}
@@ -0,0 +1,393 @@
+use std::time::Duration;
+
+use crate::{
+    clock,
+    entities::{Condition, Emotion, EntityStat, Human, Perk, Zombie},
+    rng::GameRng,
+    util::{collect_with_options, random, random_range, sleep}
+};
+
+/// Time every round of combat takes
+const ROUND_DURATION: Duration = clock::minutes(1);
+/// Damage dealt with bare hands before strength
+const UNARMED_DAMAGE: f32 = 5.0;
+const DAMAGE_PER_STRENGTH: f32 = 1.0;
+const BASE_HIT_CHANCE: f32 = 0.5;
+const HIT_CHANCE_PER_COMBAT: f32 = 0.05;
+const BASE_FLEE_CHANCE: f32 = 0.35;
+const FLEE_CHANCE_PER_LUCK: f32 = 0.04;
+/// Share of the damage still taken while defending
+const DEFEND_DAMAGE_TAKEN: f32 = 0.4;
+/// How much noise a loud weapon makes compared to a regular one
+const LOUD_NOISE: f32 = 5.0;
+/* How strongly a fight stirs up the emotions of those in it */
+const FEAR_PER_ENEMY: f32 = 0.1;
+const ANGER_PER_HIT_TAKEN: f32 = 0.05;
+const FEAR_PER_BITE: f32 = 0.4;
+const VICTORY_EXCITEMENT: f32 = 0.3;
+
+/// Anything the player can end up fighting
+#[derive(Debug)]
+pub enum Enemy {
+    Zombie(Zombie),
+    Human(Box<Human>)
+}
+
+impl Enemy {
+    pub fn get_name_formatted(&self) -> String {
+        match self {
+            Enemy::Zombie(zombie) => zombie.get_name_formatted(),
+            Enemy::Human(human) => human.get_name_formatted()
+        }
+    }
+
+    pub fn is_alive(&self) -> bool {
+        match self {
+            Enemy::Zombie(zombie) => zombie.is_alive(),
+            Enemy::Human(human) => human.is_alive()
+        }
+    }
+
+    fn get_health(&self) -> f32 {
+        match self {
+            Enemy::Zombie(zombie) => zombie.health,
+            Enemy::Human(human) => human.stats.health
+        }
+    }
+
+    fn take_damage(&mut self, damage: f32) {
+        match self {
+            Enemy::Zombie(zombie) => zombie.health = (zombie.health - damage).max(0.0),
+            Enemy::Human(human) => human.stats.add_stat(EntityStat::Health(-damage))
+        }
+    }
+
+    /// Rolls this enemy's attack. Returns the damage dealt and whether it was a bite
+    fn attack(&mut self, rng: &mut GameRng) -> Option<(f32, bool)> {
+        match self {
+            Enemy::Zombie(zombie) => {
+                let stats = zombie.get_stats();
+                if random(rng) >= stats.accuracy {
+                    return None;
+                }
+
+                let damage = random_range(rng, stats.damage * 0.75..stats.damage * 1.25);
+                Some((damage, random(rng) < stats.bite_chance))
+            },
+            Enemy::Human(human) => roll_attack(rng, human).map(|damage| (damage, false))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CombatOutcome {
+    Victory,
+    Fled,
+    Defeat
+}
+
+#[derive(Debug)]
+pub struct CombatReport {
+    pub outcome: CombatOutcome,
+    /// Enemies still standing when the fight ended
+    pub survivors: Vec<Enemy>,
+    /// Enemies that went down, along with everything they carried
+    pub fallen: Vec<Enemy>,
+    pub elapsed: Duration,
+    /// How much noise the fight made, which attracts the undead
+    pub noise: f32,
+}
+
+/// The chance a human has of landing a hit. Fear makes for shaky hands
+pub fn get_hit_chance(human: &Human) -> f32 {
+    let chance = BASE_HIT_CHANCE + HIT_CHANCE_PER_COMBAT * human.get_attributes().combat as f32;
+    (chance * human.get_mood_effects().accuracy).min(0.95)
+}
+
+/// The damage a human deals with a successful hit before any randomness.
+/// Anger and knowing how to throw a punch put more weight behind every blow
+pub fn get_base_damage(human: &Human) -> f32 {
+    let weapon = human.weapon
+        .as_ref()
+        .and_then(|weapon| weapon.damage)
+        .unwrap_or(UNARMED_DAMAGE);
+
+    let damage = (weapon + DAMAGE_PER_STRENGTH * human.get_attributes().strength as f32) * human.get_mood_effects().damage;
+
+    if human.has_perk(Perk::Brawler) {
+        damage * Perk::BRAWLER_DAMAGE_MULTIPLIER
+    } else {
+        damage
+    }
+}
+
+/// Rolls a human's attack, wearing down their weapon. Returns the damage dealt on a hit
+fn roll_attack(rng: &mut GameRng, human: &mut Human) -> Option<f32> {
+    if random(rng) >= get_hit_chance(human) {
+        return None;
+    }
+
+    let damage = get_base_damage(human);
+    let damage = random_range(rng, damage * 0.8..damage * 1.2);
+
+    if let Some(weapon) = human.weapon.as_mut() {
+        if weapon.wear(1.0) {
+            println!("{}'s {} breaks!", human.first_name, weapon.name);
+            human.weapon = None;
+        }
+    }
+
+    Some(damage)
+}
+
+/// How much noise a human makes swinging or firing their weapon
+fn get_attack_noise(human: &Human) -> f32 {
+    match &human.weapon {
+        Some(weapon) if weapon.loud => LOUD_NOISE,
+        _ => 1.0
+    }
+}
+
+fn choose_target(enemies: &[Enemy]) -> Option<usize> {
+    let alive = enemies
+        .iter()
+        .enumerate()
+        .filter(|(_, enemy)| enemy.is_alive())
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    match alive.len() {
+        0 => None,
+        1 => Some(alive[0]),
+        _ => {
+            let names = alive
+                .iter()
+                .map(|i| format!("{} ({:.0} health)", enemies[*i].get_name_formatted(), enemies[*i].get_health()))
+                .collect::<Vec<_>>();
+            let options = names.iter().map(String::as_str).collect::<Vec<_>>();
+
+            let index = collect_with_options("Who do you attack?", &options).unwrap();
+            Some(alive[index])
+        }
+    }
+}
+
+/// Lets the player pick a consumable from their inventory mid fight
+fn use_item_in_combat(player: &mut Human) -> bool {
+    let usable = player.inventory
+        .items()
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| item.category.is_consumable() || item.is_weapon())
+        .map(|(i, item)| (i, item.to_string()))
+        .collect::<Vec<_>>();
+
+    if usable.is_empty() {
+        println!("You have nothing useful on you!");
+        return false;
+    }
+
+    let mut options = usable.iter().map(|(_, name)| name.as_str()).collect::<Vec<_>>();
+    options.push("Back");
+
+    let index = collect_with_options("Which item do you use?", &options).unwrap();
+    let Some((index, _)) = usable.get(index) else {
+        return false;
+    };
+
+    match player.use_item(*index) {
+        Ok(msg) => {
+            println!("{msg}");
+            true
+        },
+        Err(err) => {
+            println!("{err}");
+            false
+        }
+    }
+}
+
+/// Picks who an enemy goes after. The player is always in the fight,
+/// companions are chosen as often as the player
+fn choose_victim(rng: &mut GameRng, companions: &[Human]) -> Option<usize> {
+    let alive = companions
+        .iter()
+        .enumerate()
+        .filter(|(_, human)| human.is_alive())
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    let roll = (random(rng) * (alive.len() + 1) as f32) as usize;
+    alive.get(roll).copied()
+}
+
+/// Runs a turn based fight between the player, their companions and a group of enemies
+pub fn run_encounter(
+    rng: &mut GameRng,
+    player: &mut Human,
+    companions: &mut [Human],
+    mut enemies: Vec<Enemy>
+) -> CombatReport {
+    let mut elapsed = Duration::ZERO;
+    let mut noise = 0.0;
+
+    let names = enemies.iter().map(Enemy::get_name_formatted).collect::<Vec<_>>();
+    println!("You face {}!\n", names.join(", "));
+
+    let dread = Emotion::Fear(FEAR_PER_ENEMY * enemies.len() as f32);
+    player.feel(dread);
+    for companion in companions.iter_mut() {
+        companion.feel(dread);
+    }
+
+    let outcome = loop {
+        if !player.is_alive() {
+            break CombatOutcome::Defeat;
+        }
+
+        if enemies.iter().all(|enemy| !enemy.is_alive()) {
+            break CombatOutcome::Victory;
+        }
+
+        println!(
+            "Health: {:.0} | Weapon: {}",
+            player.stats.health,
+            player.weapon.as_ref().map_or("Fists", |weapon| weapon.name.as_str())
+        );
+
+        let prompt = collect_with_options(
+            "What do you do?",
+            &["Attack", "Defend", "Flee", "Use an item"]
+        );
+
+        let mut defending = false;
+        match prompt.unwrap() {
+            0 => {
+                if let Some(target) = choose_target(&enemies) {
+                    noise += get_attack_noise(player);
+
+                    match roll_attack(rng, player) {
+                        Some(damage) => {
+                            enemies[target].take_damage(damage);
+                            println!("You hit {} for {:.0} damage", enemies[target].get_name_formatted(), damage);
+
+                            if !enemies[target].is_alive() {
+                                println!("{} goes down!", enemies[target].get_name_formatted());
+                            }
+                        },
+                        None => println!("You miss!")
+                    }
+                }
+            },
+            1 => {
+                defending = true;
+                println!("You brace yourself");
+            },
+            2 => {
+                let chance = BASE_FLEE_CHANCE + FLEE_CHANCE_PER_LUCK * player.get_attributes().luck as f32;
+                if random(rng) < chance {
+                    println!("You manage to get away!\n");
+                    elapsed += ROUND_DURATION;
+                    break CombatOutcome::Fled;
+                }
+
+                println!("You try to run but are cut off!");
+            },
+            3 => {
+                if !use_item_in_combat(player) {
+                    continue;
+                }
+            },
+            _ => unreachable!()
+        }
+
+        // Only rounds where the player actually did something take time
+        elapsed += ROUND_DURATION;
+
+        // Companions fight on their own
+        for companion in companions.iter_mut().filter(|companion| companion.is_alive()) {
+            let Some(target) = enemies.iter().position(Enemy::is_alive) else {
+                break;
+            };
+
+            noise += get_attack_noise(companion);
+            if let Some(damage) = roll_attack(rng, companion) {
+                enemies[target].take_damage(damage);
+                println!(
+                    "{} hits {} for {:.0} damage",
+                    companion.first_name,
+                    enemies[target].get_name_formatted(),
+                    damage
+                );
+            }
+        }
+
+        // Then whatever is left strikes back
+        for enemy in enemies.iter_mut().filter(|enemy| enemy.is_alive()) {
+            let victim = choose_victim(rng, companions);
+            let Some((damage, bite)) = enemy.attack(rng) else {
+                continue;
+            };
+
+            match victim {
+                Some(i) => {
+                    let companion = &mut companions[i];
+                    companion.stats.add_stat(EntityStat::Health(-damage));
+                    companion.feel(Emotion::Anger(ANGER_PER_HIT_TAKEN));
+                    println!("{} hits {} for {:.0} damage", enemy.get_name_formatted(), companion.first_name, damage);
+
+                    if let Some(injury) = Condition::roll_injury(rng, damage) {
+                        println!("{} has been injured ({})", companion.first_name, injury.kind);
+                        companion.add_condition(injury);
+                    }
+
+                    if bite {
+                        companion.infect();
+                        companion.feel(Emotion::Fear(FEAR_PER_BITE));
+                        println!("{} has been bitten!", companion.first_name);
+                    }
+                },
+                None => {
+                    let damage = if defending { damage * DEFEND_DAMAGE_TAKEN } else { damage };
+                    player.stats.add_stat(EntityStat::Health(-damage));
+                    player.feel(Emotion::Anger(ANGER_PER_HIT_TAKEN));
+                    println!("{} hits you for {:.0} damage", enemy.get_name_formatted(), damage);
+
+                    if let Some(injury) = Condition::roll_injury(rng, damage) {
+                        println!("You have been injured ({})", injury.kind);
+                        player.add_condition(injury);
+                    }
+
+                    // Defending keeps their teeth away
+                    if bite && !defending {
+                        player.infect();
+                        player.feel(Emotion::Fear(FEAR_PER_BITE));
+                        println!("You have been bitten!");
+                    }
+                }
+            }
+        }
+
+        println!();
+        sleep(500);
+    };
+
+    match outcome {
+        CombatOutcome::Victory => {
+            println!("The fight is over\n");
+            player.feel(Emotion::Excitement(VICTORY_EXCITEMENT));
+        },
+        CombatOutcome::Defeat => println!("You collapse...\n"),
+        CombatOutcome::Fled => {}
+    }
+
+    let (survivors, fallen) = enemies.into_iter().partition(Enemy::is_alive);
+
+    CombatReport {
+        outcome,
+        survivors,
+        fallen,
+        elapsed,
+        noise,
+    }
+}
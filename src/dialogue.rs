@@ -0,0 +1,286 @@
+use serde::{Serialize, Deserialize};
+use std::{collections::BTreeMap, sync::OnceLock};
+
+use crate::{
+    entities::{Emotion, EntityAttribute, Human, Perk, RelationshipKind},
+    items::Item,
+    rng::GameRng,
+    survivors::Attitude,
+    util::{collect_with_options, list_files, load_from_file, random_choice, sleep}
+};
+
+const DIALOGUE_FOLDER: &str = "./data/dialogue";
+
+/// Something that has to hold for a choice to be offered.
+/// Relationships are looked at from the side of whoever the player is talking to
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum DialogueCondition {
+    /// The player has at least this much of an attribute
+    MinAttribute(EntityAttribute),
+    /// The player has a perk
+    HasPerk(Perk),
+    /// The player carries at least this many of an item
+    HasItem { item: String, count: u32 },
+    /// The speaker carries at least this many of an item
+    SpeakerHasItem { item: String, count: u32 },
+    MinAffinity(f32),
+    MaxAffinity(f32),
+    MinTrust(f32),
+    Relationship(RelationshipKind)
+}
+
+impl DialogueCondition {
+    pub fn is_met(&self, player: &Human, speaker: &Human) -> bool {
+        let relationship = speaker.get_relationship(player.id);
+        let affinity = relationship.map_or(0.0, |relationship| relationship.affinity);
+        let trust = relationship.map_or(0.0, |relationship| relationship.trust);
+
+        match self {
+            DialogueCondition::MinAttribute(attribute) => {
+                *player.get_attributes().get_attribute(*attribute) >= attribute.get_value()
+            },
+            DialogueCondition::HasPerk(perk) => player.has_perk(*perk),
+            DialogueCondition::HasItem { item, count } => player.inventory.count_of(item) >= *count,
+            DialogueCondition::SpeakerHasItem { item, count } => speaker.inventory.count_of(item) >= *count,
+            DialogueCondition::MinAffinity(value) => affinity >= *value,
+            DialogueCondition::MaxAffinity(value) => affinity <= *value,
+            DialogueCondition::MinTrust(value) => trust >= *value,
+            DialogueCondition::Relationship(kind) => {
+                relationship.map_or(RelationshipKind::Stranger, |relationship| relationship.kind) == *kind
+            }
+        }
+    }
+}
+
+/// Something that happens once a node is reached or a choice is picked
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum DialogueEffect {
+    /// Changes how much the speaker likes the player
+    Affinity(f32),
+    /// Changes how much the speaker relies on the player
+    Trust(f32),
+    /// Stirs up an emotion in the speaker
+    Mood(Emotion),
+    /// The player hands items over to the speaker
+    GiveItem { item: String, count: u32 },
+    /// The speaker hands items over to the player
+    ReceiveItem { item: String, count: u32 }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DialogueChoice {
+    pub text: String,
+    #[serde(default)]
+    pub conditions: Vec<DialogueCondition>,
+    #[serde(default)]
+    pub effects: Vec<DialogueEffect>,
+    /// The node this choice leads to. The conversation ends without one
+    #[serde(default)]
+    pub next: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DialogueNode {
+    /// What the speaker says. `{name}` and `{player}` are replaced with first names
+    pub text: String,
+    #[serde(default)]
+    pub effects: Vec<DialogueEffect>,
+    #[serde(default)]
+    pub choices: Vec<DialogueChoice>,
+}
+
+/// How a conversation changed the relationship between the player and the speaker
+#[derive(Debug, Default)]
+pub struct DialogueOutcome {
+    pub affinity: f32,
+    pub trust: f32,
+    /// Items the player was handed but could not carry, for the caller to leave on the ground
+    pub dropped: Vec<Item>,
+}
+
+/// A branching conversation. Scripts are loaded from every file in `data/dialogue`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Dialogue {
+    pub id: String,
+    /// What the conversation is about, used to remember it by
+    pub description: String,
+    /// Attitudes of the speakers who may start this conversation
+    pub attitudes: Vec<Attitude>,
+    /// The node the conversation starts at
+    pub start: String,
+    pub nodes: BTreeMap<String, DialogueNode>,
+}
+
+impl Dialogue {
+    pub fn all() -> &'static [Dialogue] {
+        static DIALOGUES: OnceLock<Vec<Dialogue>> = OnceLock::new();
+
+        DIALOGUES.get_or_init(|| {
+            list_files(DIALOGUE_FOLDER, "json")
+                .unwrap_or_default()
+                .iter()
+                .map(|path| {
+                    let file = load_from_file(path)
+                        .expect("Failed to load dialogue file");
+
+                    let dialogue: Dialogue = serde_json::from_str(&file)
+                        .unwrap_or_else(|err| panic!("Failed to parse dialogue file {path}: {err}"));
+
+                    dialogue.validate()
+                        .unwrap_or_else(|err| panic!("Invalid dialogue file {path}: {err}"));
+
+                    dialogue
+                })
+                .collect()
+        })
+    }
+
+    /// Makes sure the start and every choice lead to a node that exists
+    fn validate(&self) -> Result<(), String> {
+        if !self.nodes.contains_key(&self.start) {
+            return Err(format!("start node \"{}\" does not exist", self.start));
+        }
+
+        for (id, node) in &self.nodes {
+            for next in node.choices.iter().filter_map(|choice| choice.next.as_ref()) {
+                if !self.nodes.contains_key(next) {
+                    return Err(format!("node \"{id}\" leads to \"{next}\", which does not exist"));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get(id: &str) -> Option<&'static Dialogue> {
+        Self::all().iter().find(|dialogue| dialogue.id == id)
+    }
+
+    /// Picks a conversation at random that a speaker with the given attitude would start
+    pub fn choose(rng: &mut GameRng, attitude: Attitude) -> Option<&'static Dialogue> {
+        let dialogues = Self::all()
+            .iter()
+            .filter(|dialogue| dialogue.attitudes.contains(&attitude))
+            .collect::<Vec<_>>();
+
+        random_choice(rng, &dialogues).copied()
+    }
+
+    /// Plays the conversation out between the player and a speaker.
+    /// Changes to their relationship are handed back for the caller to record
+    pub fn run(&self, player: &mut Human, speaker: &mut Human) -> DialogueOutcome {
+        let mut outcome = DialogueOutcome::default();
+        let mut current = Some(self.start.as_str());
+
+        while let Some(node) = current.and_then(|id| self.nodes.get(id)) {
+            println!("{}: \"{}\"", speaker.first_name, fill_in(&node.text, player, speaker));
+            apply_effects(&node.effects, player, speaker, &mut outcome);
+
+            let choices = node.choices
+                .iter()
+                .filter(|choice| choice.conditions.iter().all(|condition| condition.is_met(player, speaker)))
+                .collect::<Vec<_>>();
+
+            if choices.is_empty() {
+                println!();
+                sleep(1000);
+                break;
+            }
+
+            let texts = choices
+                .iter()
+                .map(|choice| fill_in(&choice.text, player, speaker))
+                .collect::<Vec<_>>();
+
+            let mut options = texts.iter().map(String::as_str).collect::<Vec<_>>();
+            options.push("Walk away");
+
+            let index = collect_with_options("What do you say?", &options).unwrap();
+            let Some(choice) = choices.get(index) else {
+                break;
+            };
+
+            apply_effects(&choice.effects, player, speaker, &mut outcome);
+            current = choice.next.as_deref();
+        }
+
+        outcome
+    }
+}
+
+/// Replaces the placeholders in a line of dialogue
+fn fill_in(text: &str, player: &Human, speaker: &Human) -> String {
+    text.replace("{name}", &speaker.first_name)
+        .replace("{player}", &player.first_name)
+}
+
+fn apply_effects(effects: &[DialogueEffect], player: &mut Human, speaker: &mut Human, outcome: &mut DialogueOutcome) {
+    for effect in effects {
+        match effect {
+            DialogueEffect::Affinity(value) => outcome.affinity += value,
+            DialogueEffect::Trust(value) => outcome.trust += value,
+            DialogueEffect::Mood(emotion) => speaker.feel(*emotion),
+            DialogueEffect::GiveItem { item, count } => {
+                let given = player.inventory.remove_by_id(item, *count);
+                if let Some(item) = Item::new(item, given) {
+                    println!("You hand over {item}");
+                    speaker.inventory.add(item);
+                }
+            },
+            DialogueEffect::ReceiveItem { item, count } => {
+                let received = speaker.inventory.remove_by_id(item, *count);
+                if let Some(item) = Item::new(item, received) {
+                    println!("You receive {item}");
+                    if let Err(item) = player.pick_up(item) {
+                        println!("{} is too heavy to carry, you leave it on the ground", item.name);
+                        outcome.dropped.push(*item);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> Dialogue {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn scripts_are_valid() {
+        for dialogue in Dialogue::all() {
+            assert!(dialogue.validate().is_ok(), "{} is invalid", dialogue.id);
+        }
+    }
+
+    #[test]
+    fn missing_start_is_invalid() {
+        let dialogue = parse(r#"{
+            "id": "test",
+            "description": "Test",
+            "attitudes": [],
+            "start": "hello",
+            "nodes": { "goodbye": { "text": "Bye" } }
+        }"#);
+
+        assert!(dialogue.validate().is_err());
+    }
+
+    #[test]
+    fn missing_next_is_invalid() {
+        let dialogue = parse(r#"{
+            "id": "test",
+            "description": "Test",
+            "attitudes": [],
+            "start": "hello",
+            "nodes": {
+                "hello": { "text": "Hi", "choices": [{ "text": "Hey", "next": "nowhere" }] }
+            }
+        }"#);
+
+        assert!(dialogue.validate().is_err());
+    }
+}
@@ -0,0 +1,36 @@
+use serde::{Serialize, Deserialize};
+
+use crate::{items::Item, rng::GameRng, world::LocationId};
+use super::{Entity, EntityId, EntityType};
+
+/// An item lying around somewhere in the world
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GroundItem {
+    pub id: EntityId,
+    pub item: Item,
+    pub location: LocationId,
+}
+
+impl GroundItem {
+    pub fn new(rng: &mut GameRng, item: Item, location: LocationId) -> Self {
+        Self {
+            id: EntityId::new(rng),
+            item,
+            location,
+        }
+    }
+}
+
+impl Entity for GroundItem {
+    fn get_id(&self) -> EntityId {
+        self.id
+    }
+
+    fn get_entity_type(&self) -> EntityType {
+        EntityType::Item
+    }
+
+    fn get_entity_save_data(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
@@ -1,40 +1,53 @@
 use serde::{Serialize, Deserialize};
+use std::{fmt, time::Duration};
 
-use crate::util::{random, random_range, read_file_lines};
+use crate::{
+    items::{Inventory, Item, ItemCategory},
+    rng::GameRng,
+    world::LocationId,
+    util::{random, random_range, read_file_lines}
+};
 use super::{
+    Condition,
+    ConditionKind,
+    Entity,
+    EntityId,
     EntityType,
     EntityAttributes,
-    EntityStats
+    Infection,
+    InfectionStage,
+    EntityStat,
+    EntityStats,
+    Emotion,
+    MoodEffects,
+    Perk,
+    Relationship,
+    RelationshipKind,
+    StatDrain
 };
 
-// Placeholder types for Item, Relationship, Quest, and Emotion
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Item;
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Relationship;
-
-#[derive(Serialize, Deserialize, Debug)]
-pub enum Emotion {
-    Happinness(f32),
-    Anger(f32),
-    Surprise(f32),
-    Excitement(f32),
-    Fear(f32),
-    Disgust(f32),
-    Neutral
-}
-
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Gender {
     Male,
     Female
 }
 
+impl fmt::Display for Gender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gender = match self {
+            Gender::Male => "Male",
+            Gender::Female => "Female"
+        };
+
+        write!(f, "{gender}")
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Human {
-    /// Whether or not the entity is an undead
-    pub undead: bool,
+    pub id: EntityId,
+    /// The zombie virus, if this human has been bitten
+    pub infection: Option<Infection>,
     /* Identifiable information about this human */ 
     pub first_name: String,
     pub last_name: String,
@@ -44,31 +57,57 @@ pub struct Human {
     pub attributes: EntityAttributes,
     pub stats: EntityStats,
 
-    // TODO: Add diseases
-    pub inventory: Vec<Item>,
+    /// Diseases and injuries besides the zombie virus
+    pub conditions: Vec<Condition>,
+    /// Passive traits picked up before the outbreak or since
+    pub perks: Vec<Perk>,
+    pub inventory: Inventory,
+    /// The weapon currently held, kept out of the inventory
+    pub weapon: Option<Item>,
     pub relationships: Vec<Relationship>,
-    pub current_location: String,
+    pub current_location: LocationId,
     pub mood: Vec<Emotion>,
     entity_type: EntityType,
 }
 
 impl Human {
-    pub fn new() -> Self {
-        let gender = if random() >= 0.5 { Gender::Male } else { Gender::Female };
+    /// Carry capacity in kilograms with no strength at all
+    const BASE_CARRY_CAPACITY: f32 = 15.0;
+    /// Extra kilograms of carry capacity per point of strength
+    const CARRY_CAPACITY_PER_STRENGTH: f32 = 3.0;
+    /// Past this multiple of the carry capacity nothing else can be picked up
+    const HARD_CARRY_LIMIT: f32 = 1.5;
+    /// How much faster energy drains and how much slower travel is while encumbered
+    pub const ENCUMBRANCE_PENALTY: f32 = 1.5;
+    /// Share of the intensity of every emotion that fades each hour
+    const MOOD_DECAY_PER_HOUR: f32 = 0.15;
+    /// Emotions fainter than this are forgotten
+    const MIN_EMOTION_INTENSITY: f32 = 0.05;
+    /// How much being in a critical state wears on the mood per hour
+    const DISTRESS_PER_HOUR: f32 = 0.05;
+    /// How bad a disease is when it is first caught
+    const NEW_CONDITION_SEVERITY: f32 = 0.3;
+
+    pub fn new(rng: &mut GameRng) -> Self {
+        let gender = if random(rng) >= 0.5 { Gender::Male } else { Gender::Female };
 
         Self {
-            age: random_range(17.0..=30.0) as u8,
-            attributes: EntityAttributes::initial_stats(),
-            current_location: String::default(),
+            id: EntityId::new(rng),
+            age: random_range(rng, 17.0..=30.0) as u8,
+            attributes: EntityAttributes::initial_stats(rng),
+            current_location: LocationId::default(),
             entity_type: EntityType::Human,
-            first_name: get_random_first_name(&gender),
+            first_name: get_random_first_name(rng, &gender),
             gender,
-            inventory: Vec::new(),
-            last_name: get_random_last_name(),
+            inventory: Inventory::default(),
+            weapon: None,
+            last_name: get_random_last_name(rng),
             mood: Vec::new(),
             relationships: Vec::new(),
             stats: EntityStats::default(),
-            undead: false
+            infection: None,
+            conditions: Vec::new(),
+            perks: Vec::new()
         }
     }
 
@@ -87,6 +126,18 @@ impl Human {
         self
     }
 
+    pub fn set_gender(&mut self, gender: Gender) -> &mut Self {
+        self.gender = gender;
+        self
+    }
+
+    /// Picks a new random name to go with the gender
+    pub fn roll_name(&mut self, rng: &mut GameRng) -> &mut Self {
+        self.first_name = get_random_first_name(rng, &self.gender);
+        self.last_name = get_random_last_name(rng);
+        self
+    }
+
     pub fn get_name_formatted(&self) -> String {
         format!("{} {}", self.first_name, self.last_name)
     }
@@ -98,39 +149,431 @@ impl Human {
     pub fn get_stats_formatted(&self) -> String {
         self.stats.get_formatted()
     }
+
+    pub fn get_relationship(&self, id: EntityId) -> Option<&Relationship> {
+        self.relationships.iter().find(|relationship| relationship.target == id)
+    }
+
+    /// The relationship with another human, meeting them as a stranger if they never met
+    pub fn get_relationship_mut(&mut self, id: EntityId) -> &mut Relationship {
+        let index = match self.relationships.iter().position(|relationship| relationship.target == id) {
+            Some(index) => index,
+            None => {
+                self.relationships.push(Relationship::new(id, RelationshipKind::Stranger));
+                self.relationships.len() - 1
+            }
+        };
+
+        &mut self.relationships[index]
+    }
+
+    pub fn remove_relationship(&mut self, id: EntityId) {
+        self.relationships.retain(|relationship| relationship.target != id);
+    }
+
+    /// How much weight this human can carry without being encumbered
+    pub fn get_carry_capacity(&self) -> f32 {
+        let capacity = Self::BASE_CARRY_CAPACITY + Self::CARRY_CAPACITY_PER_STRENGTH * self.get_attributes().strength as f32;
+
+        if self.has_perk(Perk::PackMule) {
+            capacity + Perk::PACK_MULE_CARRY_CAPACITY
+        } else {
+            capacity
+        }
+    }
+
+    /// The weight past which this human refuses to pick anything else up
+    pub fn get_carry_limit(&self) -> f32 {
+        self.get_carry_capacity() * Self::HARD_CARRY_LIMIT
+    }
+
+    /// The weight of the inventory and the held weapon
+    pub fn get_carried_weight(&self) -> f32 {
+        let weapon = self.weapon.as_ref().map_or(0.0, Item::get_total_weight);
+        self.inventory.get_total_weight() + weapon
+    }
+
+    pub fn is_encumbered(&self) -> bool {
+        self.get_carried_weight() > self.get_carry_capacity()
+    }
+
+    /// Multiplier applied to the time it takes this human to travel
+    pub fn get_travel_multiplier(&self) -> f32 {
+        if self.is_encumbered() { Self::ENCUMBRANCE_PENALTY } else { 1.0 }
+    }
+
+    pub fn get_carry_formatted(&self) -> String {
+        format!(
+            "Carrying {:.2}/{:.2}kg{}",
+            self.get_carried_weight(),
+            self.get_carry_capacity(),
+            if self.is_encumbered() { " (Encumbered)" } else { "" }
+        )
+    }
+
+    /// Puts an item into the inventory unless it would go past the carry limit,
+    /// in which case the item is handed back
+    pub fn pick_up(&mut self, item: Item) -> Result<(), Box<Item>> {
+        if self.get_carried_weight() + item.get_total_weight() > self.get_carry_limit() {
+            return Err(Box::new(item));
+        }
+
+        self.inventory.add(item);
+        Ok(())
+    }
+
+    /// Holds the weapon at the given inventory index, putting away the current one
+    pub fn equip_weapon(&mut self, index: usize) -> Result<String, &'static str> {
+        let item = self.inventory.get(index).ok_or("That item does not exist")?;
+
+        if !item.is_weapon() {
+            return Err("That item can not be wielded");
+        }
+
+        let weapon = self.inventory.take(index, 1).ok_or("That item does not exist")?;
+        let msg = format!("You are now wielding {}", weapon.name);
+
+        if let Some(previous) = self.weapon.replace(weapon) {
+            self.inventory.add(previous);
+        }
+
+        Ok(msg)
+    }
+
+    pub fn unequip_weapon(&mut self) {
+        if let Some(weapon) = self.weapon.take() {
+            self.inventory.add(weapon);
+        }
+    }
+
+    /// Uses the item at the given inventory index, applying its effects.
+    /// Weapons are wielded instead
+    pub fn use_item(&mut self, index: usize) -> Result<String, &'static str> {
+        let item = self.inventory.get(index).ok_or("That item does not exist")?;
+
+        if item.is_weapon() {
+            return self.equip_weapon(index);
+        }
+
+        if !item.category.is_consumable() {
+            return Err("That item can not be used this way");
+        }
+
+        let item = self.inventory.take(index, 1).ok_or("That item does not exist")?;
+        item.apply_effects(&mut self.stats);
+
+        let mut treated = Vec::new();
+        for condition in self.conditions.iter_mut().filter(|condition| item.treats.contains(&condition.kind)) {
+            condition.treat();
+            treated.push(condition.kind.to_string());
+        }
+
+        self.conditions.retain(|condition| !condition.is_cured());
+        for condition in &item.causes {
+            self.add_condition(*condition);
+        }
+
+        let mut msg = format!("You used {} ({})", item.name, item.get_effects_formatted());
+        if !treated.is_empty() {
+            msg.push_str(&format!("\nTreated: {}", treated.join(", ")));
+        }
+
+        Ok(msg)
+    }
+
+    /// How pleasant this human is feeling overall, from -1 (miserable) to 1 (elated)
+    pub fn get_mood_score(&self) -> f32 {
+        let score: f32 = self.mood
+            .iter()
+            .map(|emotion| match emotion {
+                Emotion::Happinness(value) => *value,
+                Emotion::Excitement(value) => value * 0.5,
+                Emotion::Anger(value) => -value,
+                Emotion::Fear(value) | Emotion::Disgust(value) => -value * 0.5,
+                Emotion::Surprise(_) | Emotion::Neutral => 0.0
+            })
+            .sum();
+
+        score.clamp(-1.0, 1.0)
+    }
+
+    /// Stirs up an emotion. Feeling something already felt makes it stronger
+    pub fn feel(&mut self, emotion: Emotion) {
+        if matches!(emotion, Emotion::Neutral) {
+            return;
+        }
+
+        match self.mood.iter_mut().find(|felt| felt.is_same_kind(&emotion)) {
+            Some(felt) => *felt = felt.with_intensity(felt.get_intensity() + emotion.get_intensity()),
+            None => self.mood.push(emotion.with_intensity(emotion.get_intensity()))
+        }
+
+        self.mood.retain(|felt| felt.get_intensity() >= Self::MIN_EMOTION_INTENSITY);
+    }
+
+    /// Lets every emotion fade a little over the given amount of in-game hours
+    fn decay_mood(&mut self, hours: f32) {
+        let remaining = (1.0 - Self::MOOD_DECAY_PER_HOUR).powf(hours);
+
+        for emotion in self.mood.iter_mut() {
+            *emotion = emotion.with_intensity(emotion.get_intensity() * remaining);
+        }
+
+        self.mood.retain(|felt| felt.get_intensity() >= Self::MIN_EMOTION_INTENSITY);
+    }
+
+    /// The strongest emotion this human feels, if any
+    pub fn get_dominant_emotion(&self) -> Emotion {
+        self.mood
+            .iter()
+            .max_by(|a, b| a.get_intensity().total_cmp(&b.get_intensity()))
+            .copied()
+            .unwrap_or(Emotion::Neutral)
+    }
+
+    /// How the dominant emotion changes what this human does
+    pub fn get_mood_effects(&self) -> MoodEffects {
+        MoodEffects::from_emotion(&self.get_dominant_emotion())
+    }
+
+    pub fn get_mood_formatted(&self) -> String {
+        match self.get_dominant_emotion() {
+            Emotion::Neutral => String::from("Calm"),
+            emotion => format!("{} ({:.0}%)", emotion, emotion.get_intensity() * 100.0)
+        }
+    }
+
+    /// Charisma after the mood has had its say
+    pub fn get_charisma(&self) -> f32 {
+        (self.get_attributes().charisma as f32 + self.get_mood_effects().charisma).max(0.0)
+    }
+
+    /// Eats and drinks from the inventory once thirst or hunger run high,
+    /// and patches up whatever ails them if they have the medicine for it.
+    /// Used by everybody the player is not controlling
+    pub fn tend_to_needs(&mut self) {
+        if self.stats.is_thirsty() {
+            self.consume_first(ItemCategory::Water);
+        }
+
+        if self.stats.is_hungry() {
+            self.consume_first(ItemCategory::Food);
+        }
+
+        let medicine = self.inventory
+            .items()
+            .iter()
+            .position(|item| self.conditions.iter().any(|condition| item.treats.contains(&condition.kind)));
+
+        if let Some(index) = medicine {
+            let _ = self.use_item(index);
+        }
+    }
+
+    /// Uses the first item of a category in the inventory, if there is one
+    fn consume_first(&mut self, category: ItemCategory) {
+        if let Some(index) = self.inventory.items().iter().position(|item| item.category == category) {
+            let _ = self.use_item(index);
+        }
+    }
+
+    /// A human that has turned is no longer alive, whatever their health says
+    pub fn is_alive(&self) -> bool {
+        self.stats.health > 0.0 && !self.has_turned()
+    }
+
+    pub fn is_infected(&self) -> bool {
+        self.infection.is_some()
+    }
+
+    pub fn has_turned(&self) -> bool {
+        self.infection.is_some_and(|infection| infection.has_turned())
+    }
+
+    /// The human has been bitten. Being bitten again does not reset the clock
+    pub fn infect(&mut self) {
+        self.infection.get_or_insert_with(Infection::default);
+    }
+
+    /// Attributes after diseases and injuries have taken their toll
+    pub fn get_attributes(&self) -> EntityAttributes {
+        let mut attributes = self.attributes.clone();
+
+        for penalty in self.conditions.iter().flat_map(Condition::get_attribute_penalties) {
+            attributes.sub_attribute(penalty);
+        }
+
+        attributes
+    }
+
+    pub fn has_perk(&self, perk: Perk) -> bool {
+        self.perks.contains(&perk)
+    }
+
+    /// Picks up a perk. Returns false if this human already had it
+    pub fn add_perk(&mut self, perk: Perk) -> bool {
+        if self.has_perk(perk) {
+            return false;
+        }
+
+        self.perks.push(perk);
+        true
+    }
+
+    /// Picks up every perk whose attribute threshold has been reached,
+    /// conditions aside. Returns the perks that are new
+    pub fn unlock_perks(&mut self) -> Vec<Perk> {
+        let unlocked = Perk::ALL
+            .into_iter()
+            .filter(|perk| {
+                perk.get_unlock().is_some_and(|unlock| {
+                    *self.attributes.get_attribute(unlock) >= unlock.get_value()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        unlocked.into_iter().filter(|perk| self.add_perk(*perk)).collect()
+    }
+
+    pub fn has_condition(&self, kind: ConditionKind) -> bool {
+        self.conditions.iter().any(|condition| condition.kind == kind)
+    }
+
+    /// Comes down with a disease or picks up an injury. Getting the same thing again makes it worse
+    pub fn add_condition(&mut self, condition: Condition) {
+        match self.conditions.iter_mut().find(|other| other.kind == condition.kind) {
+            Some(other) => other.severity = (other.severity + condition.severity).min(Condition::MAX_SEVERITY),
+            None => self.conditions.push(condition)
+        }
+    }
+
+    /// Rolls for open wounds festering and for catching the flu while worn out.
+    /// Returns whatever was newly caught
+    pub fn roll_conditions(&mut self, rng: &mut GameRng, elapsed: Duration) -> Vec<ConditionKind> {
+        let hours = elapsed.as_secs_f32() / 3600.0;
+        let mut caught = Vec::new();
+
+        let festering = self.conditions.iter().any(|condition| condition.roll_infection(rng, hours));
+        if festering && !self.has_condition(ConditionKind::Infection) {
+            caught.push(ConditionKind::Infection);
+        }
+
+        if self.stats.is_energy_critical() && !self.has_condition(ConditionKind::Flu) && Condition::roll_flu(rng, hours) {
+            caught.push(ConditionKind::Flu);
+        }
+
+        for kind in caught.iter().copied() {
+            self.add_condition(Condition::new(kind, Self::NEW_CONDITION_SEVERITY));
+        }
+
+        caught
+    }
+
+    /// Rest lets most diseases and injuries heal faster, at the given multiple of the regular rate
+    pub fn heal_while_resting(&mut self, hours: f32, recovery: f32) {
+        for condition in self.conditions.iter_mut() {
+            condition.rest(hours * recovery);
+        }
+
+        self.conditions.retain(|condition| !condition.is_cured());
+    }
+
+    /// Lets the given amount of in-game time pass for this human
+    pub fn update(&mut self, elapsed: Duration) {
+        if !self.is_alive() {
+            return;
+        }
+
+        let hours = elapsed.as_secs_f32() / 3600.0;
+        self.stats.decay(hours, self.get_stat_drain(), self.get_mood_effects().recovery);
+        self.decay_mood(hours);
+
+        // Going hungry and thirsty makes anybody short tempered
+        if self.stats.is_thirst_critical() || self.stats.is_hunger_critical() {
+            self.feel(Emotion::Anger(Self::DISTRESS_PER_HOUR * hours));
+        }
+
+        if let Some(infection) = self.infection.as_mut() {
+            infection.progress(hours);
+            self.stats.add_stat(EntityStat::Thirst(Infection::THIRST_PER_HOUR * hours));
+
+            if infection.get_stage() != InfectionStage::Early {
+                self.stats.add_stat(EntityStat::Health(-Infection::DAMAGE_PER_HOUR * hours));
+                self.feel(Emotion::Fear(Self::DISTRESS_PER_HOUR * hours));
+            }
+        }
+
+        for condition in self.conditions.iter_mut() {
+            for effect in condition.get_stat_effects(hours) {
+                self.stats.add_stat(effect);
+            }
+
+            condition.progress(hours);
+        }
+
+        self.conditions.retain(|condition| !condition.is_cured());
+    }
+
+    /// How fast this human gets thirsty, hungry and tired, perks and load included
+    fn get_stat_drain(&self) -> StatDrain {
+        let mut drain = StatDrain::default();
+
+        if self.has_perk(Perk::Insomniac) {
+            drain.energy *= 1.0 - Perk::INSOMNIAC_ENERGY_SAVING;
+        }
+
+        if self.has_perk(Perk::IronStomach) {
+            drain.thirst *= 1.0 - Perk::IRON_STOMACH_SAVING;
+            drain.hunger *= 1.0 - Perk::IRON_STOMACH_SAVING;
+        }
+
+        if self.is_encumbered() {
+            drain.energy *= Self::ENCUMBRANCE_PENALTY;
+        }
+
+        drain
+    }
+}
+
+impl Entity for Human {
+    fn get_id(&self) -> EntityId {
+        self.id
+    }
+
+    fn get_entity_type(&self) -> EntityType {
+        self.entity_type
+    }
+
+    fn get_entity_save_data(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
 }
 
-fn get_random_first_name(gender: &Gender) -> String {
+fn get_random_first_name(rng: &mut GameRng, gender: &Gender) -> String {
     let gender = match gender {
         Gender::Male => "male",
         Gender::Female => "female"
     };
 
-    match read_file_lines(&format!("./random/first-names-{}.txt", gender)) {
-        Ok(lines) => {
-            let index = (random() * lines.len() as f32) as usize;
+    if let Ok(lines) = read_file_lines(&format!("./random/first-names-{}.txt", gender)) {
+        let index = (random(rng) * lines.len() as f32) as usize;
 
-            if let Some(name) = lines.get(index) {
-                return name.to_owned();
-            }
-        },
-        Err(_) => {},
+        if let Some(name) = lines.get(index) {
+            return name.to_owned();
+        }
     }
 
     String::new()
 }
 
-fn get_random_last_name() -> String {
-    match read_file_lines("./random/last-names.txt") {
-        Ok(lines) => {
-            let index = random_range(0.0..=lines.len() as f32) as usize;
+fn get_random_last_name(rng: &mut GameRng) -> String {
+    if let Ok(lines) = read_file_lines("./random/last-names.txt") {
+        let index = random_range(rng, 0.0..lines.len() as f32) as usize;
 
-            if let Some(name) = lines.get(index) {
-                return name.to_owned();
-            }
-        },
-        Err(_) => {},
+        if let Some(name) = lines.get(index) {
+            return name.to_owned();
+        }
     }
 
     String::new()
-}
\ No newline at end of file
+}
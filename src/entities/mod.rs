@@ -1,18 +1,40 @@
 use serde::{Serialize, Deserialize};
 
+use crate::rng::GameRng;
+
+mod ground_item;
 mod human;
 mod player;
+mod registry;
 mod structs;
+mod zombie;
 
-pub use human::Human;
+pub use ground_item::GroundItem;
+pub use human::{Gender, Human};
 pub use player::Player;
+pub use registry::{EntityRegistry, RegisteredEntity};
 pub use structs::*;
+pub use zombie::{Zombie, ZombieKind, ZombieStats};
+
+/// Uniquely identifies an entity across saves and loads
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EntityId(pub u64);
+
+impl EntityId {
+    pub fn new(rng: &mut GameRng) -> Self {
+        Self(rng.next_u64())
+    }
+}
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EntityType {
-    Human
+    Human,
+    Zombie,
+    Item
 }
 
 pub trait Entity {
+    fn get_id(&self) -> EntityId;
+    fn get_entity_type(&self) -> EntityType;
     fn get_entity_save_data(&self) -> String;
 }
\ No newline at end of file
@@ -0,0 +1,332 @@
+use std::fmt;
+
+use crate::{
+    entities::{EntityAttribute, EntityAttributes, Gender, Human, Perk},
+    game::Game,
+    items::Item,
+    util::{collect_input, collect_input_str, collect_with_options}
+};
+use super::Player;
+
+const MIN_AGE: u8 = 17;
+const MAX_AGE: u8 = 70;
+/// Names longer than this are cut short
+const MAX_NAME_LENGTH: usize = 20;
+/// Points to spend when buying attributes instead of rolling them
+const POINT_BUY_POINTS: u8 = 12;
+/// The most points a single attribute can be bought up to
+const POINT_BUY_MAX: u8 = 5;
+/// Points the archetype adds to the attribute that defines the player
+const ARCHETYPE_POINTS: u8 = 3;
+
+/// What the player did for a living before the outbreak
+#[derive(Debug, Clone, Copy)]
+enum Background {
+    Nurse,
+    Cop,
+    Mechanic,
+    Student
+}
+
+impl Background {
+    const ALL: [Background; 4] = [
+        Background::Nurse,
+        Background::Cop,
+        Background::Mechanic,
+        Background::Student
+    ];
+
+    /// Attribute points gained from the job
+    fn get_bonuses(&self) -> &'static [EntityAttribute] {
+        match self {
+            Background::Nurse => &[EntityAttribute::Intelligence(1), EntityAttribute::Charisma(1)],
+            Background::Cop => &[EntityAttribute::Combat(2)],
+            Background::Mechanic => &[EntityAttribute::Strength(2)],
+            Background::Student => &[EntityAttribute::Intelligence(1), EntityAttribute::Luck(1)]
+        }
+    }
+
+    /// Attribute points the job took its toll on
+    fn get_penalties(&self) -> &'static [EntityAttribute] {
+        match self {
+            Background::Nurse => &[],
+            Background::Cop => &[EntityAttribute::Charisma(1)],
+            Background::Mechanic => &[EntityAttribute::Charisma(1)],
+            Background::Student => &[EntityAttribute::Strength(1)]
+        }
+    }
+
+    /// Items brought along from work
+    fn get_items(&self) -> &'static [(&'static str, u32)] {
+        match self {
+            Background::Nurse => &[("bandage", 2), ("painkillers", 1), ("antibiotics", 1)],
+            Background::Cop => &[("pistol", 1), ("flashlight", 1)],
+            Background::Mechanic => &[("crowbar", 1), ("scrap_metal", 2), ("nails", 4)],
+            Background::Student => &[("chocolate_bar", 2), ("soda_can", 1), ("lockpick", 1)]
+        }
+    }
+
+    fn get_option_formatted(&self) -> String {
+        let bonuses = self.get_bonuses().iter().map(|attr| format!("+{} {}", attr.get_value(), attr));
+        let penalties = self.get_penalties().iter().map(|attr| format!("-{} {}", attr.get_value(), attr));
+        let items = self.get_items()
+            .iter()
+            .filter_map(|(id, count)| Item::new(id, *count))
+            .map(|item| item.to_string());
+
+        format!(
+            "{} ({}) | {}",
+            self,
+            bonuses.chain(penalties).collect::<Vec<_>>().join(", "),
+            items.collect::<Vec<_>>().join(", ")
+        )
+    }
+}
+
+impl fmt::Display for Background {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let background = match self {
+            Background::Nurse => "Nurse",
+            Background::Cop => "Cop",
+            Background::Mechanic => "Mechanic",
+            Background::Student => "Student"
+        };
+
+        write!(f, "{background}")
+    }
+}
+
+impl Player {
+    /// Walks the player through making their survivor, step by step,
+    /// starting over for as long as they are not happy with the result
+    pub(super) fn create_character(game: &mut Game) -> Human {
+        println!(
+            "You wake up after a long slumber...\n\
+            You begin to wonder a lot of things about yourself...\n"
+        );
+
+        loop {
+            let mut human = Human::new(&mut game.rng);
+            human.current_location = game.world.start;
+
+            Self::choose_gender(game, &mut human);
+            Self::choose_name(game, &mut human);
+            Self::choose_age(&mut human);
+            human.attributes = Self::choose_attributes(game);
+
+            let background = Self::choose_background(&mut human);
+            Self::choose_archetype(&mut human);
+            human.unlock_perks();
+
+            for (id, count) in Self::STARTING_ITEMS {
+                if let Some(item) = Item::new(id, count) {
+                    human.inventory.add(item);
+                }
+            }
+
+            if Self::confirm_character(&human, background) {
+                return human;
+            }
+        }
+    }
+
+    fn choose_gender(game: &mut Game, human: &mut Human) {
+        let prompt = collect_with_options("Who are you?", &["A man", "A woman"]);
+        let gender = match prompt.unwrap() {
+            0 => Gender::Male,
+            1 => Gender::Female,
+            _ => unreachable!()
+        };
+
+        human.set_gender(gender).roll_name(&mut game.rng);
+    }
+
+    fn choose_name(game: &mut Game, human: &mut Human) {
+        loop {
+            let prompt = collect_with_options(
+                &format!("Your name is {}", human.get_name_formatted()),
+                &["Keep it", "Enter a name", "Pick another one"]
+            );
+
+            match prompt.unwrap() {
+                0 => return,
+                1 => {
+                    let (Some(first_name), Some(last_name)) = (
+                        Self::collect_name("What is your first name?"),
+                        Self::collect_name("What is your last name?")
+                    ) else {
+                        println!("Everybody needs a name\n");
+                        continue;
+                    };
+
+                    human.set_first_name(&first_name).set_last_name(&last_name);
+                },
+                2 => {
+                    human.roll_name(&mut game.rng);
+                },
+                _ => unreachable!()
+            }
+        }
+    }
+
+    fn collect_name(prompt: &str) -> Option<String> {
+        let name = collect_input_str(Some(prompt)).ok()?;
+        let name = name.trim().chars().take(MAX_NAME_LENGTH).collect::<String>();
+
+        (!name.is_empty()).then_some(name)
+    }
+
+    fn choose_age(human: &mut Human) {
+        loop {
+            let prompt = format!("How old are you? (Respond with {MIN_AGE}-{MAX_AGE})");
+
+            match collect_input::<u8>(Some(&prompt)) {
+                Ok(age) if (MIN_AGE..=MAX_AGE).contains(&age) => {
+                    human.set_age(age);
+                    return;
+                },
+                _ => println!("That doesn't look like a valid response. Please try again")
+            }
+        }
+    }
+
+    /// Lets the player roll their attributes as many times as they like, or spend points on them
+    fn choose_attributes(game: &mut Game) -> EntityAttributes {
+        let prompt = collect_with_options(
+            "How would you like to decide your attributes?",
+            &["Leave it to chance", &format!("Spend {POINT_BUY_POINTS} points")]
+        );
+
+        if prompt.unwrap() == 1 {
+            return Self::buy_attributes();
+        }
+
+        loop {
+            let attributes = EntityAttributes::initial_stats(&mut game.rng);
+            let prompt = collect_with_options(
+                &format!("You rolled:\n{}\n", attributes.get_formatted()),
+                &["Keep these", "Roll again"]
+            );
+
+            if prompt.unwrap() == 0 {
+                return attributes;
+            }
+        }
+    }
+
+    /// Lets the player spend points on every attribute, starting over
+    /// if they would rather not leave any of them unspent
+    fn buy_attributes() -> EntityAttributes {
+        loop {
+            let mut attributes = EntityAttributes::default();
+            let mut remaining = POINT_BUY_POINTS;
+
+            for attr in EntityAttribute::ALL {
+                loop {
+                    let max = remaining.min(POINT_BUY_MAX);
+                    let prompt = format!("How many points into {attr}? {remaining} left (Respond with 0-{max})");
+
+                    match collect_input::<u8>(Some(&prompt)) {
+                        Ok(points) if points <= max => {
+                            attributes.set_attribute(attr.with_value(points));
+                            remaining -= points;
+                            break;
+                        },
+                        _ => println!("That doesn't look like a valid response. Please try again")
+                    }
+                }
+            }
+
+            if remaining == 0 {
+                return attributes;
+            }
+
+            let prompt = collect_with_options(
+                &format!("You have {remaining} points left unspent. They will be lost"),
+                &["Keep these", "Spend them again"]
+            );
+
+            if prompt.unwrap() == 0 {
+                return attributes;
+            }
+        }
+    }
+
+    fn choose_background(human: &mut Human) -> Background {
+        let options = Background::ALL
+            .iter()
+            .map(Background::get_option_formatted)
+            .collect::<Vec<_>>();
+        let options = options.iter().map(String::as_str).collect::<Vec<_>>();
+
+        let prompt = collect_with_options("What did you do before all of this?", &options);
+        let background = Background::ALL[prompt.unwrap()];
+
+        for bonus in background.get_bonuses() {
+            human.attributes.add_attribute(*bonus);
+        }
+
+        for penalty in background.get_penalties() {
+            human.attributes.sub_attribute(*penalty);
+        }
+
+        for (id, count) in background.get_items() {
+            if let Some(item) = Item::new(id, *count) {
+                human.inventory.add(item);
+            }
+        }
+
+        background
+    }
+
+    /// The attribute that defines the player, which comes with a perk
+    fn choose_archetype(human: &mut Human) {
+        let prompt = collect_with_options(
+            "What attribute best defines you?",
+            &[
+                &format!("Thinker (+{} Intelligence, {})", ARCHETYPE_POINTS, Perk::Insomniac),
+                &format!("Hard worker (+{} Strength, {})", ARCHETYPE_POINTS, Perk::IronStomach),
+                &format!("Socialite (+{} Charisma, {})", ARCHETYPE_POINTS, Perk::SilverTongue),
+                &format!("Fortunate (+{} Luck, {})", ARCHETYPE_POINTS, Perk::ScavengersEye),
+                &format!("Fighter (+{} Combat, {})", ARCHETYPE_POINTS, Perk::Brawler)
+            ]
+        );
+
+        let (attribute, perk) = match prompt.unwrap() {
+            0 => (EntityAttribute::Intelligence(ARCHETYPE_POINTS), Perk::Insomniac),
+            1 => (EntityAttribute::Strength(ARCHETYPE_POINTS), Perk::IronStomach),
+            2 => (EntityAttribute::Charisma(ARCHETYPE_POINTS), Perk::SilverTongue),
+            3 => (EntityAttribute::Luck(ARCHETYPE_POINTS), Perk::ScavengersEye),
+            4 => (EntityAttribute::Combat(ARCHETYPE_POINTS), Perk::Brawler),
+            _ => unreachable!()
+        };
+
+        human.attributes.add_attribute(attribute);
+        human.add_perk(perk);
+    }
+
+    /// Shows the finished survivor. Returns false if the player would rather start over
+    fn confirm_character(human: &Human, background: Background) -> bool {
+        let perks = human.perks.iter().map(Perk::get_formatted).collect::<Vec<_>>();
+
+        println!(
+            "Name: {}\n\
+            Gender: {}\n\
+            Age: {}\n\
+            Background: {}\n\n\
+            Attributes:\n{}\n\n\
+            Perks:\n{}\n\n\
+            You carry:\n{}\n",
+            human.get_name_formatted(),
+            human.gender,
+            human.age,
+            background,
+            human.get_attributes_formatted(),
+            perks.join("\n"),
+            human.inventory
+        );
+
+        let prompt = collect_with_options("Is this you?", &["Yes, let's begin", "No, start over"]);
+        prompt.unwrap() == 0
+    }
+}
@@ -0,0 +1,85 @@
+use crate::entities::{EntityAttribute, Perk};
+use super::Player;
+
+/// Experience earned for every enemy put down, and for coming out of a fight on top
+pub(super) const KILL_EXPERIENCE: u8 = 3;
+pub(super) const VICTORY_EXPERIENCE: u8 = 2;
+/// Experience earned for every search, and for every item turned up
+pub(super) const SCAVENGE_EXPERIENCE: u8 = 2;
+pub(super) const ITEM_FOUND_EXPERIENCE: u8 = 1;
+/// Experience earned for striking a deal, and for talking somebody round
+pub(super) const TRADE_EXPERIENCE: u8 = 4;
+pub(super) const PERSUASION_EXPERIENCE: u8 = 2;
+/// Strength experience earned per kilometre walked with a full load
+const CARRY_EXPERIENCE_PER_KM: f32 = 4.0;
+/// Loads lighter than this share of the carry capacity are no workout
+const MIN_TRAINING_LOAD: f32 = 0.5;
+
+impl Player {
+    /// Puts an attribute to use, announcing every point it goes up by
+    /// and any perk that comes with it
+    pub(super) fn gain_experience(&mut self, attr: EntityAttribute) {
+        let attr = if self.human.has_perk(Perk::QuickStudy) {
+            let amount = (attr.get_value() as f32 * Perk::QUICK_STUDY_EXPERIENCE_MULTIPLIER).round() as u8;
+            attr.with_value(amount)
+        } else {
+            attr
+        };
+
+        let gained = self.experience.gain(&mut self.human.attributes, attr);
+        if gained == 0 {
+            return;
+        }
+
+        println!(
+            "Your {} has improved to {}!",
+            attr,
+            self.human.attributes.get_attribute(attr)
+        );
+
+        for perk in self.human.unlock_perks() {
+            println!("You have picked up a new perk: {}", perk.get_formatted());
+        }
+    }
+
+    /// Walking with a heavy load builds strength, the heavier the better
+    pub(super) fn train_carrying(&mut self, distance: f32) {
+        let load = self.human.get_carried_weight() / self.human.get_carry_capacity();
+        if load < MIN_TRAINING_LOAD {
+            return;
+        }
+
+        let experience = (CARRY_EXPERIENCE_PER_KM * distance * load).round() as u8;
+        self.gain_experience(EntityAttribute::Strength(experience));
+    }
+
+    /// Every attribute after conditions, along with how close it is to the next point
+    pub(super) fn get_attributes_formatted(&self) -> String {
+        let attributes = self.human.get_attributes();
+
+        EntityAttribute::ALL
+            .iter()
+            .map(|attr| {
+                format!(
+                    "{}: {} ({})",
+                    attr,
+                    attributes.get_attribute(*attr),
+                    self.experience.get_progress_formatted(&self.human.attributes, *attr)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub(super) fn get_perks_formatted(&self) -> String {
+        if self.human.perks.is_empty() {
+            return String::from("None");
+        }
+
+        self.human.perks
+            .iter()
+            .map(Perk::get_formatted)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
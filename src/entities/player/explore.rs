@@ -0,0 +1,225 @@
+use std::time::Duration;
+
+use crate::{
+    clock,
+    combat::{CombatOutcome, Enemy},
+    entities::{EntityStat, Zombie},
+    game::Game,
+    util::{collect_with_options, random, random_range, sleep}
+};
+use super::Player;
+
+/// Time it takes to move between rooms
+const ROOM_DURATION: Duration = clock::minutes(5);
+/// Time it takes to search a room
+const SEARCH_DURATION: Duration = clock::minutes(10);
+/// Time every attempt at a locked door takes
+const LOCK_DURATION: Duration = clock::minutes(5);
+/// Energy spent trying to force a door open
+const FORCE_ENERGY: f32 = 0.05;
+/// Tools that open locked doors and how much they wear down doing so
+const LOCK_TOOLS: [(&str, f32); 2] = [("lockpick", 5.0), ("crowbar", 10.0)];
+/// Chance of dormant undead waking up when somebody walks in, before luck
+const WAKE_CHANCE: f32 = 0.4;
+const WAKE_CHANCE_PER_LUCK: f32 = 0.03;
+
+impl Player {
+    /// Goes inside the building at the current location and explores it room by room.
+    /// Returns the in-game time spent doing so
+    pub(super) fn explore_building(&mut self, game: &mut Game) -> Duration {
+        let location = self.human.current_location;
+        let mut elapsed = Duration::ZERO;
+
+        loop {
+            let Some(interior) = game.world.get_interior_mut(location) else {
+                println!("There is nothing to go inside of here\n");
+                sleep(1000);
+                return elapsed;
+            };
+
+            let rooms = interior.rooms.iter().map(|room| room.get_formatted()).collect::<Vec<_>>();
+            let mut options = rooms.iter().map(String::as_str).collect::<Vec<_>>();
+            options.push("Leave the building");
+
+            let index = collect_with_options("Where would you like to go?", &options).unwrap();
+            if index >= rooms.len() {
+                return elapsed;
+            }
+
+            elapsed += ROOM_DURATION;
+
+            if !self.open_door(game, index, &mut elapsed) {
+                continue;
+            }
+
+            if !self.face_dormant_zombies(game, index, &mut elapsed) {
+                if !self.human.is_alive() {
+                    return elapsed;
+                }
+
+                continue;
+            }
+
+            self.search_room(game, index, &mut elapsed);
+        }
+    }
+
+    /// Gets through the door of a room, if it is locked. Returns whether the room is open
+    fn open_door(&mut self, game: &mut Game, room: usize, elapsed: &mut Duration) -> bool {
+        let location = self.human.current_location;
+        let Some(difficulty) = game.world
+            .get_interior_mut(location)
+            .and_then(|interior| interior.rooms[room].lock)
+        else {
+            return true;
+        };
+
+        let tool = LOCK_TOOLS
+            .iter()
+            .find_map(|(id, wear)| self.human.inventory.position_of(id).map(|index| (index, *wear)));
+
+        let tool_option = tool.map(|(index, _)| format!("Use your {}", self.human.inventory.items()[index].name));
+        let mut options = vec!["Force it open (Strength)"];
+        if let Some(tool_option) = &tool_option {
+            options.push(tool_option);
+        }
+        options.push("Back");
+
+        let prompt = collect_with_options("The door is locked", &options).unwrap();
+
+        let opened = match (prompt, tool) {
+            (0, _) => {
+                *elapsed += LOCK_DURATION;
+                self.human.stats.add_stat(EntityStat::Energy(-FORCE_ENERGY));
+                let roll = self.human.get_attributes().strength as f32 + random_range(&mut game.rng, 0.0..6.0);
+                roll >= difficulty as f32
+            },
+            (1, Some((index, wear))) => {
+                *elapsed += LOCK_DURATION;
+                if let Some(tool) = self.human.inventory.get_mut(index) {
+                    if tool.wear(wear) {
+                        println!("Your {} breaks in the process!", tool.name);
+                        self.human.inventory.take(index, 1);
+                    }
+                }
+
+                true
+            },
+            _ => return false
+        };
+
+        if !opened {
+            println!("The door will not budge\n");
+            sleep(1000);
+            return false;
+        }
+
+        println!("The door gives way\n");
+        if let Some(interior) = game.world.get_interior_mut(location) {
+            interior.rooms[room].lock = None;
+        }
+
+        true
+    }
+
+    /// Deals with any undead sleeping in a room. Returns whether it is safe to search
+    fn face_dormant_zombies(&mut self, game: &mut Game, room: usize, elapsed: &mut Duration) -> bool {
+        let location = self.human.current_location;
+        let dormant = game.world
+            .get_interior_mut(location)
+            .map_or(0, |interior| interior.rooms[room].dormant_zombies);
+
+        if dormant == 0 {
+            return true;
+        }
+
+        let wake_chance = WAKE_CHANCE - WAKE_CHANCE_PER_LUCK * self.human.get_attributes().luck as f32;
+        let woke_up = random(&mut game.rng) < wake_chance;
+
+        if !woke_up {
+            let prompt = collect_with_options(
+                &format!("{} undead lie dormant in here...", dormant),
+                &["Strike while they sleep", "Back away quietly"]
+            );
+
+            if prompt.unwrap() == 1 {
+                return false;
+            }
+        } else {
+            println!("Something groans in the dark. They are awake!\n");
+        }
+
+        let enemies = (0..dormant)
+            .map(|_| {
+                let mut zombie = Zombie::new(&mut game.rng, location);
+
+                // Catching them asleep gives the first blow for free
+                if !woke_up {
+                    zombie.health *= 0.5;
+                }
+
+                Enemy::Zombie(zombie)
+            })
+            .collect();
+
+        let report = self.fight(game, enemies);
+        *elapsed += report.elapsed;
+
+        if let Some(interior) = game.world.get_interior_mut(location) {
+            interior.rooms[room].dormant_zombies = report.survivors.len() as u32;
+        }
+
+        report.outcome == CombatOutcome::Victory
+    }
+
+    /// Goes through whatever is stashed away in a room
+    fn search_room(&mut self, game: &mut Game, room: usize, elapsed: &mut Duration) {
+        let location = self.human.current_location;
+
+        loop {
+            let Some(interior) = game.world.get_interior_mut(location) else {
+                return;
+            };
+
+            let room = &mut interior.rooms[room];
+            if !room.explored {
+                room.explored = true;
+                *elapsed += SEARCH_DURATION;
+            }
+
+            if room.container.is_empty() {
+                println!("There is nothing left in the {}\n", room.kind);
+                sleep(1000);
+                return;
+            }
+
+            let items = room.container.items().iter().map(|item| item.to_string()).collect::<Vec<_>>();
+            let mut options = items.iter().map(String::as_str).collect::<Vec<_>>();
+            options.push("Take everything");
+            options.push("Back");
+
+            let index = collect_with_options(&format!("You search the {}", room.kind), &options).unwrap();
+            let taking = match index {
+                i if i < items.len() => vec![i],
+                i if i == items.len() => (0..items.len()).rev().collect(),
+                _ => return
+            };
+
+            for i in taking {
+                let Some(item) = room.container.take(i, u32::MAX) else {
+                    continue;
+                };
+
+                match self.human.pick_up(item) {
+                    Ok(()) => println!("You take {}", items[i]),
+                    Err(item) => {
+                        println!("{} is too heavy to carry", item.name);
+                        room.container.add(*item);
+                    }
+                }
+            }
+
+            println!();
+        }
+    }
+}
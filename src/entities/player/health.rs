@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+use crate::{
+    clock,
+    items::ItemCategory,
+    util::{collect_with_options, sleep}
+};
+use super::Player;
+
+/// Time it takes to clean a wound or take some medicine
+const TREAT_DURATION: Duration = clock::minutes(10);
+
+impl Player {
+    /// Shows every disease and injury the player has and lets them treat those with medicine.
+    /// Returns the in-game time spent doing so
+    pub(super) fn print_health_menu(&mut self) -> Duration {
+        let mut elapsed = Duration::ZERO;
+
+        loop {
+            println!("Health: {:.0}", self.human.stats.health);
+
+            if let Some(infection) = self.human.infection {
+                println!("Zombie Virus ({}) - There is no known cure", infection.get_stage());
+            }
+
+            for condition in &self.human.conditions {
+                println!("{}", condition.get_formatted());
+            }
+
+            if self.human.infection.is_none() && self.human.conditions.is_empty() {
+                println!("You feel fine");
+            }
+
+            println!();
+
+            let prompt = collect_with_options("What would you like to do?", &["Use medicine", "Back"]);
+            if prompt.unwrap() != 0 {
+                return elapsed;
+            }
+
+            let medicines = self.human.inventory
+                .items()
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| item.category == ItemCategory::Medicine)
+                .map(|(index, item)| {
+                    let treats = item.treats.iter().map(|kind| kind.to_string()).collect::<Vec<_>>();
+                    (index, format!("{} - Treats: {}", item, treats.join(", ")))
+                })
+                .collect::<Vec<_>>();
+
+            if medicines.is_empty() {
+                println!("You don't have any medicine\n");
+                sleep(1000);
+                continue;
+            }
+
+            let mut options = medicines.iter().map(|(_, line)| line.as_str()).collect::<Vec<_>>();
+            options.push("Back");
+
+            let index = collect_with_options("Which medicine do you use?", &options).unwrap();
+            let Some((index, _)) = medicines.get(index) else {
+                continue;
+            };
+
+            match self.human.use_item(*index) {
+                Ok(msg) => {
+                    println!("{msg}\n");
+                    elapsed += TREAT_DURATION;
+                },
+                Err(err) => println!("{err}\n")
+            }
+
+            sleep(1000);
+        }
+    }
+}
@@ -0,0 +1,521 @@
+use serde::{Serialize, Deserialize};
+use std::{
+    io,
+    sync::{Arc, Mutex},
+    time::Duration
+};
+
+use crate::{
+    clock,
+    util::{collect_with_options, save_to_file_bin, load_from_file_bin, sleep},
+    entities::{Emotion, EntityAttribute, EntityStat, EntityStats, Experience, GroundItem, Human, RegisteredEntity},
+    game::{Game, GameTickSignal},
+    items::{InventorySort, ItemCategory, LootTable},
+    combat::{CombatOutcome, Enemy},
+    party::{CompanionRole, Party},
+    saves::SaveSlot,
+    scavenge::{get_zombie_chance, roll_ambush, scavenge, SCAVENGE_DURATION},
+    survivors::roll_survivors,
+    world::World
+};
+
+mod creation;
+mod experience;
+mod explore;
+mod health;
+mod party;
+mod safehouse;
+mod scouting;
+mod survivors;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Player {
+    pub human: Human,
+    /// What other survivors have heard of the player, from -100 (feared) to 100 (trusted)
+    pub reputation: f32,
+    /// Survivors who agreed to stick with the player
+    pub party: Party,
+    /// Progress towards the next point of every attribute
+    pub experience: Experience,
+    #[serde(skip)]
+    pub game: Arc<Mutex<Game>>
+}
+
+impl Player {
+    pub const MIN_REPUTATION: f32 = -100.0;
+    pub const MAX_REPUTATION: f32 = 100.0;
+    /// How much finding food or water lifts the player's spirits
+    const FOOD_FOUND_HAPPINESS: f32 = 0.2;
+    /// Emotions at least this strong show up in the status line
+    const STRONG_EMOTION: f32 = 0.5;
+    /// Items every survivor wakes up with
+    const STARTING_ITEMS: [(&'static str, u32); 4] = [
+        ("water_bottle", 2),
+        ("canned_beans", 2),
+        ("chocolate_bar", 1),
+        ("bandage", 1)
+    ];
+
+    pub fn load(game: &Arc<Mutex<Game>>, slot: &SaveSlot) -> Result<Self, &'static str> {
+        let file = load_from_file_bin(&slot.get_player_path())
+            .expect("Failed to load player file");
+
+        // There is no data stored yet
+        // Create a new one and return here
+        if file.is_empty() {
+            return Err("Player does not exist");
+        }
+
+        let mut player: Self = bincode::deserialize(&file)
+            .map_err(|_| "This save is from an incompatible version")?;
+
+        player.game = Arc::clone(game);
+        Ok(player)        
+    }
+
+    pub fn save(&self, slot: &SaveSlot) -> Result<(), io::Error> {
+        let serialized = bincode::serialize(self)
+            .expect("Failed to serialize player");
+
+        save_to_file_bin(&slot.get_player_path(), &serialized)
+    }
+
+    pub fn init(game: Arc<Mutex<Game>>) -> Self {
+        let slot = game.lock().unwrap().slot.clone();
+        let player = Self::load(&game, &slot);
+
+        if let Ok(player) = player {
+            player
+        } else {
+            let player = Self::new(&game);
+            let _ = player.save(&slot);
+            player
+        }
+    }
+
+    // A new survivor is created...
+    pub fn new(game: &Arc<Mutex<Game>>) -> Self {
+        let human = Self::create_character(&mut game.lock().unwrap());
+
+        Self {
+            human,
+            reputation: 0.0,
+            party: Party::default(),
+            experience: Experience::default(),
+            game: Arc::clone(game),
+        }
+    }
+
+    /// Changes what other survivors think of the player
+    pub fn add_reputation(&mut self, amount: f32) {
+        self.reputation = (self.reputation + amount).clamp(Self::MIN_REPUTATION, Self::MAX_REPUTATION);
+    }
+
+    pub fn print_status(&self, game: &Game) {
+        println!(
+            "{} (Age: {}) | {} ({}) | Day {}",
+            self.human.get_name_formatted(),
+            self.human.age,
+            game.get_time_formatted(),
+            game.get_day_phase(),
+            game.get_days_survived() + 1
+        );
+
+        if let Some(location) = game.world.get(self.human.current_location) {
+            println!("{}, {}", location, Game::CITY);
+        }
+
+        let mut warnings = self.human.stats
+            .get_warnings()
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+
+        if self.human.is_encumbered() {
+            warnings.push(String::from("Encumbered"));
+        }
+
+        if let Some(infection) = self.human.infection {
+            warnings.push(format!("Infected ({})", infection.get_stage()));
+        }
+
+        for condition in &self.human.conditions {
+            warnings.push(condition.to_string());
+        }
+
+        let emotion = self.human.get_dominant_emotion();
+        if emotion.get_intensity() >= Self::STRONG_EMOTION {
+            warnings.push(emotion.to_string());
+        }
+
+        if !warnings.is_empty() {
+            println!(
+                "Health: {:.0} | Warning: {}",
+                self.human.stats.health,
+                warnings.join(", ")
+            );
+        }
+    }
+
+    pub fn print_menu(&mut self, game: &mut Game) -> GameTickSignal {
+        self.print_status(game);
+
+        let prompt = collect_with_options(
+            "", 
+            &[
+               "Display Stats",
+               "Display Attributes",
+               "Health",
+               "Inventory",
+               "Scavenge (1 hour)",
+               "Explore building",
+               "Travel",
+               "Scout the area (30 min)",
+               "Survivors nearby",
+               "Party",
+               "Safehouse",
+               "Rest (1 hour)",
+               "Exit"
+            ]
+        );
+
+        match prompt.unwrap() {
+            0 => {
+                println!("{}\nMood: {}", self.human.stats.get_formatted(), self.human.get_mood_formatted());
+                sleep(2000);
+            },
+            1 => {
+                println!("{}\n\nPerks:\n{}", self.get_attributes_formatted(), self.get_perks_formatted());
+                if !self.human.conditions.is_empty() {
+                    println!("(Your condition is holding you back)");
+                }
+                sleep(2000);
+            },
+            2 => {
+                let elapsed = self.print_health_menu();
+                return GameTickSignal::Continue(elapsed);
+            },
+            3 => {
+                let elapsed = self.print_inventory_menu(game);
+                return GameTickSignal::Continue(elapsed);
+            },
+            4 => {
+                let elapsed = self.scavenge(game);
+                return GameTickSignal::Continue(elapsed);
+            },
+            5 => {
+                let elapsed = self.explore_building(game);
+                return GameTickSignal::Continue(elapsed);
+            },
+            6 => {
+                let elapsed = self.travel(game);
+                return GameTickSignal::Continue(elapsed);
+            },
+            7 => {
+                let elapsed = self.scout(game);
+                return GameTickSignal::Continue(elapsed);
+            },
+            8 => {
+                let elapsed = self.meet_survivors(game);
+                return GameTickSignal::Continue(elapsed);
+            },
+            9 => {
+                let elapsed = self.print_party_menu(game);
+                return GameTickSignal::Continue(elapsed);
+            },
+            10 => {
+                let elapsed = self.print_safehouse_menu(game);
+                return GameTickSignal::Continue(elapsed);
+            },
+            11 => {
+                println!("You rest for an hour...");
+                let mut recovery = self.human.get_mood_effects().recovery;
+                if self.party.has_role(CompanionRole::Guard) {
+                    println!("Knowing somebody is keeping watch, you sleep soundly");
+                    recovery *= Party::GUARD_REST_MULTIPLIER;
+                }
+
+                let safehouse = game.safehouse
+                    .as_ref()
+                    .filter(|safehouse| safehouse.location == self.human.current_location);
+
+                if let Some(safehouse) = safehouse {
+                    println!("It feels good to rest somewhere safe");
+                    recovery *= safehouse.get_rest_multiplier();
+                }
+
+                self.human.stats.add_stat(EntityStat::Energy(EntityStats::REST_ENERGY_PER_HOUR * recovery));
+                self.human.heal_while_resting(1.0, recovery);
+                return GameTickSignal::Continue(clock::hours(1));
+            },
+            12 => return GameTickSignal::Exit,
+            _ => unreachable!()
+        };
+
+        GameTickSignal::Continue(Duration::ZERO)
+    }
+
+    /// How much less likely ambushes are thanks to a scout keeping an eye out
+    fn get_ambush_multiplier(&self) -> f32 {
+        if self.party.has_role(CompanionRole::Scout) { Party::SCOUT_AMBUSH_MULTIPLIER } else { 1.0 }
+    }
+
+    /// Searches the surroundings for loot, fighting off whatever lurks there first.
+    /// Returns the in-game time spent doing so
+    fn scavenge(&mut self, game: &mut Game) -> Duration {
+        println!("You search your surroundings for anything useful...\n");
+
+        let location = self.human.current_location;
+        let zombie_chance = get_zombie_chance(game.world.location(location)) * self.get_ambush_multiplier();
+
+        let day_phase = game.get_day_phase();
+        let ambush = roll_ambush(&mut game.rng, zombie_chance, day_phase, location);
+
+        if !ambush.is_empty() {
+            let enemies = ambush.into_iter().map(Enemy::Zombie).collect();
+            let report = self.fight(game, enemies);
+
+            if report.outcome != CombatOutcome::Victory {
+                return report.elapsed;
+            }
+        }
+
+        let Some(location) = game.world.get_mut(location) else {
+            return SCAVENGE_DURATION;
+        };
+
+        let mut report = scavenge(&mut game.rng, &mut self.human, location);
+
+        // Scavengers search alongside the player, handing over what they find
+        for id in self.party.with_role(CompanionRole::Scavenger) {
+            let Some(companion) = game.entities.get_human(id) else {
+                continue;
+            };
+
+            let loot_table = LootTable::get(location.kind.get_loot_table_id());
+            for item in loot_table.roll(&mut game.rng, &companion.attributes, location.richness) {
+                match self.human.pick_up(item.clone()) {
+                    Ok(()) => report.found.push(item),
+                    Err(item) => report.left_behind.push(*item)
+                }
+            }
+        }
+
+        println!("{}", report.get_formatted());
+
+        // Knowing where to look and getting lucky both come with practice
+        let found = (report.found.len() as u8).saturating_mul(experience::ITEM_FOUND_EXPERIENCE);
+        let amount = experience::SCAVENGE_EXPERIENCE.saturating_add(found);
+        self.gain_experience(EntityAttribute::Luck(amount));
+        self.gain_experience(EntityAttribute::Intelligence(amount));
+
+        // Finding something to eat or drink is a small victory
+        if report.found.iter().any(|item| matches!(item.category, ItemCategory::Food | ItemCategory::Water)) {
+            self.human.feel(Emotion::Happinness(Self::FOOD_FOUND_HAPPINESS));
+        }
+        sleep(2000);
+
+        SCAVENGE_DURATION
+    }
+
+    /// Walks to a neighbouring location, possibly running into the undead on the way.
+    /// Returns the in-game time spent doing so
+    fn travel(&mut self, game: &mut Game) -> Duration {
+        let neighbours = game.world.get_neighbours(self.human.current_location);
+        let travel_multiplier = self.human.get_travel_multiplier();
+
+        let destinations = neighbours
+            .iter()
+            .map(|(id, distance)| {
+                let location = game.world.location(*id);
+                let duration = World::get_travel_duration(*distance, travel_multiplier);
+
+                format!(
+                    "{} - {:.1}km, {} ({})",
+                    location,
+                    distance,
+                    clock::format_duration(duration),
+                    location.get_danger_formatted()
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut options = destinations.iter().map(String::as_str).collect::<Vec<_>>();
+        options.push("Back");
+
+        let index = collect_with_options("Where would you like to go?", &options).unwrap();
+        let Some((destination, distance)) = neighbours.get(index).copied() else {
+            return Duration::ZERO;
+        };
+
+        let mut elapsed = World::get_travel_duration(distance, travel_multiplier);
+        self.human.stats.add_stat(EntityStat::Energy(-World::ENERGY_PER_KM * distance * travel_multiplier));
+        self.train_carrying(distance);
+
+        let zombie_chance = World::TRAVEL_ZOMBIE_CHANCE
+            * game.world.location(destination).get_danger()
+            * self.get_ambush_multiplier();
+        let day_phase = game.get_day_phase();
+        let ambush = roll_ambush(&mut game.rng, zombie_chance, day_phase, destination);
+
+        if !ambush.is_empty() {
+            println!("Something stirs on the road ahead...\n");
+
+            let enemies = ambush.into_iter().map(Enemy::Zombie).collect();
+            let report = self.fight(game, enemies);
+            elapsed += report.elapsed;
+
+            // Fleeing sends the player back the way they came
+            if report.outcome != CombatOutcome::Victory {
+                return elapsed;
+            }
+        }
+
+        self.human.current_location = destination;
+        println!("You arrive at {}\n", game.world.location(destination).get_formatted());
+        sleep(2000);
+
+        self.move_party(game);
+
+        let group = roll_survivors(&mut game.rng, game.world.location(destination));
+        if let Some(id) = group.first().map(|survivor| survivor.id) {
+            let names = group.iter().map(Human::get_name_formatted).collect::<Vec<_>>();
+            match names.len() {
+                1 => println!("You run into {}, another survivor\n", names[0]),
+                _ => println!("You run into a group of survivors: {}\n", names.join(", "))
+            }
+
+            for survivor in group {
+                game.entities.insert(RegisteredEntity::Human(survivor));
+            }
+
+            elapsed += self.interact_with_survivor(game, id);
+        }
+
+        elapsed
+    }
+
+    /// Lets the player inspect, use, drop and sort their items.
+    /// Returns the in-game time spent doing so
+    fn print_inventory_menu(&mut self, game: &mut Game) -> Duration {
+        const USE_DURATION: Duration = clock::minutes(5);
+        let mut elapsed = Duration::ZERO;
+
+        loop {
+            println!(
+                "{}\nWielding: {}\n{}\n",
+                self.human.inventory,
+                self.human.weapon.as_ref().map_or(String::from("Nothing"), |weapon| weapon.to_string()),
+                self.human.get_carry_formatted()
+            );
+
+            let prompt = collect_with_options(
+                "What would you like to do?",
+                &["Inspect an item", "Use an item", "Drop an item", "Sort", "Pick up items here", "Back"]
+            );
+
+            match prompt.unwrap() {
+                0 => {
+                    if let Some(index) = self.choose_item("Which item would you like to inspect?") {
+                        println!("{}\n", self.human.inventory.items()[index].get_formatted());
+                        sleep(2000);
+                    }
+                },
+                1 => {
+                    if let Some(index) = self.choose_item("Which item would you like to use?") {
+                        match self.human.use_item(index) {
+                            Ok(msg) => {
+                                println!("{msg}\n");
+                                elapsed += USE_DURATION;
+                            },
+                            Err(err) => println!("{err}\n")
+                        }
+                        sleep(1000);
+                    }
+                },
+                2 => {
+                    if let Some(index) = self.choose_item("Which item would you like to drop?") {
+                        let count = self.human.inventory.items()[index].count;
+                        if let Some(item) = self.human.inventory.take(index, count) {
+                            println!("You dropped {item}\n");
+
+                            let item = GroundItem::new(&mut game.rng, item, self.human.current_location);
+                            game.entities.insert(RegisteredEntity::Item(item));
+                        }
+                    }
+                },
+                3 => {
+                    let sort = collect_with_options(
+                        "Sort your inventory by",
+                        &["Name", "Category", "Weight"]
+                    );
+
+                    match sort.unwrap() {
+                        0 => self.human.inventory.sort(InventorySort::Name),
+                        1 => self.human.inventory.sort(InventorySort::Category),
+                        2 => self.human.inventory.sort(InventorySort::Weight),
+                        _ => unreachable!()
+                    }
+                },
+                4 => self.print_pick_up_menu(game),
+                5 => return elapsed,
+                _ => unreachable!()
+            }
+        }
+    }
+
+    /// Lists the items lying on the ground where the player stands
+    fn print_pick_up_menu(&mut self, game: &mut Game) {
+        loop {
+            let ground_items = game.entities
+                .items_at(self.human.current_location)
+                .map(|item| (item.id, item.item.to_string()))
+                .collect::<Vec<_>>();
+
+            if ground_items.is_empty() {
+                println!("There is nothing lying around here\n");
+                sleep(1000);
+                return;
+            }
+
+            let mut options = ground_items.iter().map(|(_, name)| name.as_str()).collect::<Vec<_>>();
+            options.push("Back");
+
+            let index = collect_with_options("Which item would you like to pick up?", &options).unwrap();
+            let Some((id, _)) = ground_items.get(index) else {
+                return;
+            };
+
+            if let Some(RegisteredEntity::Item(ground_item)) = game.entities.remove(*id) {
+                match self.human.pick_up(ground_item.item) {
+                    Ok(()) => println!("You picked it up\n"),
+                    Err(item) => {
+                        println!("{} is too heavy to carry\n", item.name);
+                        game.entities.insert(RegisteredEntity::Item(GroundItem { item: *item, ..ground_item }));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Lists every inventory item as an option. Returns `None` if the player backs out
+    fn choose_item(&self, text: &str) -> Option<usize> {
+        if self.human.inventory.is_empty() {
+            println!("You aren't carrying anything\n");
+            sleep(1000);
+            return None;
+        }
+
+        let items = self.human.inventory
+            .items()
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>();
+
+        let mut options = items.iter().map(String::as_str).collect::<Vec<_>>();
+        options.push("Back");
+
+        let index = collect_with_options(text, &options).unwrap();
+        (index < items.len()).then_some(index)
+    }
+}
@@ -0,0 +1,320 @@
+use std::time::Duration;
+
+use crate::{
+    clock,
+    combat::{run_encounter, CombatOutcome, CombatReport, Enemy},
+    entities::{Emotion, EntityAttribute, EntityId, EntityStat, GroundItem, Human, RegisteredEntity},
+    game::Game,
+    items::ItemCategory,
+    party::{CompanionRole, Party},
+    survivors::get_attitude,
+    util::{collect_with_options, random, random_range, sleep}
+};
+use super::{experience, Player};
+
+/// Time it takes to hand something over to a companion
+const GIVE_DURATION: Duration = clock::minutes(5);
+/// How hard losing a companion hits the player
+const COMPANION_LOSS_EMOTION: f32 = 0.4;
+/// How much companions warm up to the player when fed and after a fight won together
+const FED_AFFINITY: f32 = 1.0;
+const VICTORY_AFFINITY: f32 = 2.0;
+/// How much companions resent going hungry or thirsty per hour
+const NEGLECT_AFFINITY_PER_HOUR: f32 = 2.0;
+/// Affinity gained per point of value of a gift
+const AFFINITY_PER_GIFT_VALUE: f32 = 0.2;
+
+impl Player {
+    /// Fights alongside every companion. Whoever survives goes back to the registry
+    pub(super) fn fight(&mut self, game: &mut Game, enemies: Vec<Enemy>) -> CombatReport {
+        let mut companions = self.party.take_members(&mut game.entities);
+        let report = run_encounter(&mut game.rng, &mut self.human, &mut companions, enemies);
+
+        // Every undead put down is one less roaming around, but the racket carries
+        let location = self.human.current_location;
+        let killed = report.fallen.iter().filter(|enemy| matches!(enemy, Enemy::Zombie(_))).count();
+        game.world.thin_hordes(location, killed as u32);
+        game.world.make_noise(location, report.noise);
+
+        let mut amount = (report.fallen.len() as u8).saturating_mul(experience::KILL_EXPERIENCE);
+        if report.outcome == CombatOutcome::Victory {
+            amount = amount.saturating_add(experience::VICTORY_EXPERIENCE);
+        }
+        self.gain_experience(EntityAttribute::Combat(amount));
+
+        if report.outcome == CombatOutcome::Victory {
+            for companion in companions.iter_mut().filter(|companion| companion.is_alive()) {
+                companion.get_relationship_mut(self.human.id).adjust(VICTORY_AFFINITY, 0.0);
+            }
+        }
+
+        self.party.return_members(&mut game.entities, companions);
+        report
+    }
+
+    /// Has the whole party follow the player to a new location
+    pub(super) fn move_party(&self, game: &mut Game) {
+        for member in self.party.members() {
+            if let Some(companion) = game.entities.get_human_mut(member.id) {
+                companion.current_location = self.human.current_location;
+            }
+        }
+    }
+
+    /// Lets the party live through the given amount of in-game time. Companions
+    /// eat and drink from the player's inventory, medics patch the player up and
+    /// those who no longer want to be around walk away, or worse
+    pub fn update_party(&mut self, game: &mut Game, elapsed: Duration, turned: &[EntityId]) {
+        let hours = elapsed.as_secs_f32() / 3600.0;
+
+        self.mourn_companions(game, turned);
+        self.share_supplies(game, hours);
+
+        let medics = self.party.with_role(CompanionRole::Medic).count();
+        self.human.stats.add_stat(EntityStat::Health(Party::MEDIC_HEALING_PER_HOUR * medics as f32 * hours));
+
+        self.check_loyalty(game, hours);
+    }
+
+    /// Takes companions who died or turned out of the party
+    fn mourn_companions(&mut self, game: &mut Game, turned: &[EntityId]) {
+        let lost = self.party
+            .members()
+            .iter()
+            .filter(|member| game.entities.get_human(member.id).is_none())
+            .map(|member| member.id)
+            .collect::<Vec<_>>();
+
+        for id in lost {
+            let Some(member) = self.party.remove(id) else {
+                continue;
+            };
+
+            if turned.contains(&id) {
+                self.human.feel(Emotion::Fear(COMPANION_LOSS_EMOTION));
+                self.human.feel(Emotion::Disgust(COMPANION_LOSS_EMOTION));
+            } else {
+                println!("{} didn't make it...\n", member.name);
+                self.human.feel(Emotion::Anger(COMPANION_LOSS_EMOTION));
+            }
+        }
+    }
+
+    /// Companions who ran out of their own food and water take from the player's
+    fn share_supplies(&mut self, game: &mut Game, hours: f32) {
+        for member in self.party.members() {
+            let Some(companion) = game.entities.get_human_mut(member.id) else {
+                continue;
+            };
+
+            let needs = [
+                (ItemCategory::Water, companion.stats.is_thirsty()),
+                (ItemCategory::Food, companion.stats.is_hungry())
+            ];
+
+            for (category, in_need) in needs {
+                if !in_need {
+                    continue;
+                }
+
+                let index = self.human.inventory
+                    .items()
+                    .iter()
+                    .position(|item| item.category == category);
+
+                let affinity = match index.and_then(|index| self.human.inventory.take(index, 1)) {
+                    Some(item) => {
+                        println!("{} takes {} from the shared supplies", companion.first_name, item.name);
+                        item.apply_effects(&mut companion.stats);
+                        FED_AFFINITY
+                    },
+                    None => -NEGLECT_AFFINITY_PER_HOUR * hours
+                };
+
+                companion.get_relationship_mut(self.human.id).adjust(affinity, 0.0);
+            }
+        }
+    }
+
+    /// Companions who lost faith in the player may leave. The angry ones take something with them
+    fn check_loyalty(&mut self, game: &mut Game, hours: f32) {
+        let chance = Party::DESERTION_CHANCE_PER_HOUR * hours;
+        let deserters = self.party
+            .members()
+            .iter()
+            .filter_map(|member| {
+                let companion = game.entities.get_human(member.id)?;
+                let loyalty = Party::get_loyalty(companion, self.human.id);
+                let angry = matches!(companion.get_dominant_emotion(), Emotion::Anger(_));
+
+                (loyalty < Party::LEAVE_LOYALTY).then_some((member.id, loyalty < Party::BETRAY_LOYALTY && angry))
+            })
+            .collect::<Vec<_>>();
+
+        for (id, betrayal) in deserters {
+            if random(&mut game.rng) >= chance {
+                continue;
+            }
+
+            let Some(member) = self.party.remove(id) else {
+                continue;
+            };
+
+            let stolen = (betrayal && !self.human.inventory.is_empty())
+                .then(|| {
+                    let index = random_range(&mut game.rng, 0.0..self.human.inventory.len() as f32) as usize;
+                    self.human.inventory.take(index.min(self.human.inventory.len() - 1), u32::MAX)
+                })
+                .flatten();
+
+            let Some(companion) = game.entities.get_human_mut(id) else {
+                continue;
+            };
+
+            let location = companion.current_location;
+            let mut dropped = None;
+
+            match stolen {
+                Some(item) => {
+                    println!("{} has run off with your {}!\n", member.name, item);
+                    // Whatever is too heavy to run off with gets left behind
+                    if let Err(item) = companion.pick_up(item) {
+                        dropped = Some(*item);
+                    }
+
+                    companion.get_relationship_mut(self.human.id).record(game.time, "Betrayed the party", -30.0, -50.0);
+                    self.human.get_relationship_mut(id).record(game.time, "Betrayed the party", -30.0, -50.0);
+                    self.human.feel(Emotion::Anger(COMPANION_LOSS_EMOTION));
+                },
+                None => {
+                    println!("{} has decided to go their own way\n", member.name);
+                    companion.get_relationship_mut(self.human.id).record(game.time, "Left the party", -5.0, -10.0);
+                    self.human.get_relationship_mut(id).record(game.time, "Left the party", -5.0, -10.0);
+                }
+            }
+
+            if let Some(item) = dropped {
+                let item = GroundItem::new(&mut game.rng, item, location);
+                game.entities.insert(RegisteredEntity::Item(item));
+            }
+
+            sleep(1000);
+        }
+    }
+
+    /// Lists the party and lets the player manage each companion.
+    /// Returns the in-game time spent doing so
+    pub(super) fn print_party_menu(&mut self, game: &mut Game) -> Duration {
+        let mut elapsed = Duration::ZERO;
+
+        loop {
+            let members = self.party
+                .members()
+                .iter()
+                .filter_map(|member| {
+                    let companion = game.entities.get_human(member.id)?;
+                    Some((member.id, self.get_companion_formatted(companion, member.role)))
+                })
+                .collect::<Vec<_>>();
+
+            if members.is_empty() {
+                println!("You are on your own\n");
+                sleep(1000);
+                return elapsed;
+            }
+
+            let mut options = members.iter().map(|(_, line)| line.as_str()).collect::<Vec<_>>();
+            options.push("Back");
+
+            let index = collect_with_options("Your party", &options).unwrap();
+            let Some((id, _)) = members.get(index) else {
+                return elapsed;
+            };
+
+            elapsed += self.print_companion_menu(game, *id);
+        }
+    }
+
+    fn get_companion_formatted(&self, companion: &Human, role: Option<CompanionRole>) -> String {
+        let relationship = companion
+            .get_relationship(self.human.id)
+            .map_or(String::from("Stranger"), |relationship| relationship.kind.to_string());
+
+        format!(
+            "{} - {} | Health: {:.0} | {} | {}",
+            companion.get_name_formatted(),
+            role.map_or(String::from("No role"), |role| role.to_string()),
+            companion.stats.health,
+            companion.get_mood_formatted(),
+            relationship
+        )
+    }
+
+    fn print_companion_menu(&mut self, game: &mut Game, id: EntityId) -> Duration {
+        let Some(companion) = game.entities.get_human(id) else {
+            return Duration::ZERO;
+        };
+
+        let first_name = companion.first_name.clone();
+        let attitude = get_attitude(companion, self.human.id, self.reputation);
+
+        let prompt = collect_with_options(
+            &format!("What about {first_name}?"),
+            &["Assign a role", "Talk", "Give an item", "Dismiss", "Back"]
+        );
+
+        match prompt.unwrap() {
+            0 => {
+                let roles = CompanionRole::ALL
+                    .iter()
+                    .map(|role| format!("{} - {}", role, role.get_description()))
+                    .collect::<Vec<_>>();
+
+                let mut options = roles.iter().map(String::as_str).collect::<Vec<_>>();
+                options.push("No role");
+
+                let index = collect_with_options(&format!("What should {first_name} do?"), &options).unwrap();
+                if let Some(member) = self.party.get_mut(id) {
+                    member.role = CompanionRole::ALL.get(index).copied();
+                }
+
+                Duration::ZERO
+            },
+            1 => self.talk_to_survivor(game, id, attitude),
+            2 => {
+                let Some(index) = self.choose_item(&format!("What do you give {first_name}?")) else {
+                    return Duration::ZERO;
+                };
+
+                let (Some(item), Some(companion)) = (self.human.inventory.take(index, 1), game.entities.get_human_mut(id)) else {
+                    return Duration::ZERO;
+                };
+
+                let affinity = AFFINITY_PER_GIFT_VALUE * item.get_total_value();
+                match companion.pick_up(item) {
+                    Ok(()) => {
+                        println!("{first_name} gladly takes it\n");
+                        companion.feel(Emotion::Happinness(affinity / 10.0));
+                        self.record_interaction(game, id, "Received a gift", affinity, 2.0);
+                    },
+                    Err(item) => {
+                        println!("{first_name} can't carry any more\n");
+                        self.human.inventory.add(*item);
+                    }
+                }
+
+                sleep(1000);
+                GIVE_DURATION
+            },
+            3 => {
+                self.party.remove(id);
+                println!("{first_name} goes their own way\n");
+                self.record_interaction(game, id, "Was sent away", -5.0, -5.0);
+                sleep(1000);
+
+                Duration::ZERO
+            },
+            _ => Duration::ZERO
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use std::time::Duration;
+
+use crate::{
+    clock,
+    entities::{Emotion, EntityStat, GroundItem, RegisteredEntity},
+    game::Game,
+    safehouse::{Safehouse, StructureKind},
+    util::{collect_with_options, sleep}
+};
+use super::Player;
+
+/// Time it takes to look a place over and make it livable
+const CLAIM_DURATION: Duration = clock::minutes(30);
+/// How shaken the player is by a raid they were there for
+const RAID_FEAR: f32 = 0.4;
+
+impl Player {
+    /// Lets time pass at the safehouse. Raids only hurt the player if they are there to see it
+    pub fn update_safehouse(&mut self, game: &mut Game, elapsed: Duration) {
+        let day_phase = game.get_day_phase();
+        let Some(safehouse) = game.safehouse.as_mut() else {
+            return;
+        };
+
+        let danger = game.world.location(safehouse.location).get_danger();
+        let report = safehouse.update(&mut game.rng, elapsed, danger, day_phase);
+
+        if safehouse.location != self.human.current_location {
+            return;
+        }
+
+        for item in &report.produced {
+            println!("You add {item} to the stash");
+        }
+
+        let Some(raid) = report.raid else {
+            return;
+        };
+
+        println!("The undead break into your safehouse!");
+        self.human.stats.add_stat(EntityStat::Health(-raid.damage));
+        self.human.feel(Emotion::Fear(RAID_FEAR));
+        println!("You take {:.0} damage fighting them off", raid.damage);
+
+        for item in &raid.lost {
+            println!("{item} was ruined");
+        }
+
+        if raid.fortification_broken {
+            println!("Part of the barricades were torn down");
+        }
+
+        println!();
+        sleep(2000);
+    }
+
+    /// Lets the player claim a safehouse, or use the one they are standing in.
+    /// Returns the in-game time spent doing so
+    pub(super) fn print_safehouse_menu(&mut self, game: &mut Game) -> Duration {
+        let Some(safehouse) = &game.safehouse else {
+            return self.claim_safehouse(game);
+        };
+
+        if safehouse.location != self.human.current_location {
+            println!(
+                "Your safehouse is at {}\n{}\n",
+                game.world.location(safehouse.location),
+                safehouse.get_formatted()
+            );
+            sleep(2000);
+            return Duration::ZERO;
+        }
+
+        let mut elapsed = Duration::ZERO;
+
+        loop {
+            let Some(safehouse) = &game.safehouse else {
+                return elapsed;
+            };
+
+            println!("{}\n", safehouse.get_formatted());
+
+            let prompt = collect_with_options(
+                "What would you like to do?",
+                &["Stash an item", "Take an item", "Build", "Abandon", "Back"]
+            );
+
+            match prompt.unwrap() {
+                0 => self.stash_item(game),
+                1 => self.take_from_stash(game),
+                2 => elapsed += self.build_structure(game),
+                3 => self.abandon_safehouse(game),
+                _ => return elapsed
+            }
+        }
+    }
+
+    fn claim_safehouse(&mut self, game: &mut Game) -> Duration {
+        let location = game.world.location(self.human.current_location);
+
+        if !location.has_interior() {
+            println!("There is nowhere to hole up around here\n");
+            sleep(1000);
+            return Duration::ZERO;
+        }
+
+        let prompt = collect_with_options(
+            &format!("Claim {} as your safehouse?", location.name),
+            &["Yes", "No"]
+        );
+
+        if prompt.unwrap() != 0 {
+            return Duration::ZERO;
+        }
+
+        println!("You clear out a room and make {} your own\n", location.name);
+        game.safehouse = Some(Safehouse::new(self.human.current_location));
+        sleep(1000);
+
+        CLAIM_DURATION
+    }
+
+    fn stash_item(&mut self, game: &mut Game) {
+        let Some(safehouse) = game.safehouse.as_mut() else {
+            return;
+        };
+
+        if self.human.inventory.is_empty() {
+            println!("You have nothing to stash\n");
+            sleep(1000);
+            return;
+        }
+
+        let Some(index) = self.choose_item("Which item would you like to stash?") else {
+            return;
+        };
+
+        let count = self.human.inventory.items()[index].count;
+        if let Some(item) = self.human.inventory.take(index, count) {
+            println!("You stash {item}\n");
+            safehouse.stash.add(item);
+        }
+    }
+
+    fn take_from_stash(&mut self, game: &mut Game) {
+        let Some(safehouse) = game.safehouse.as_mut() else {
+            return;
+        };
+
+        if safehouse.stash.is_empty() {
+            println!("The stash is empty\n");
+            sleep(1000);
+            return;
+        }
+
+        let items = safehouse.stash
+            .items()
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>();
+
+        let mut options = items.iter().map(String::as_str).collect::<Vec<_>>();
+        options.push("Back");
+
+        let index = collect_with_options("Which item would you like to take?", &options).unwrap();
+        let count = match safehouse.stash.get(index) {
+            Some(item) => item.count,
+            None => return
+        };
+
+        if let Some(item) = safehouse.stash.take(index, count) {
+            match self.human.pick_up(item) {
+                Ok(()) => println!("You take it\n"),
+                Err(item) => {
+                    println!("{} is too heavy to carry\n", item.name);
+                    safehouse.stash.add(*item);
+                }
+            }
+        }
+    }
+
+    /// Spends materials from the stash, then the inventory, on a structure.
+    /// Returns the in-game time spent building
+    fn build_structure(&mut self, game: &mut Game) -> Duration {
+        let Some(safehouse) = game.safehouse.as_mut() else {
+            return Duration::ZERO;
+        };
+
+        let structures = StructureKind::ALL
+            .iter()
+            .map(|kind| {
+                format!(
+                    "{} ({}/{}) - {} | Cost: {}",
+                    kind,
+                    safehouse.get_level(*kind),
+                    Safehouse::MAX_LEVEL,
+                    kind.get_description(),
+                    kind.get_cost_formatted()
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut options = structures.iter().map(String::as_str).collect::<Vec<_>>();
+        options.push("Back");
+
+        let index = collect_with_options("What would you like to build?", &options).unwrap();
+        let Some(kind) = StructureKind::ALL.get(index).copied() else {
+            return Duration::ZERO;
+        };
+
+        if !safehouse.can_build(kind) {
+            println!("There is no room for another {kind}\n");
+            sleep(1000);
+            return Duration::ZERO;
+        }
+
+        let affordable = kind.get_cost()
+            .iter()
+            .all(|(id, count)| safehouse.stash.count_of(id) + self.human.inventory.count_of(id) >= *count);
+
+        if !affordable {
+            println!("You don't have the materials for a {kind}\n");
+            sleep(1000);
+            return Duration::ZERO;
+        }
+
+        for (id, count) in kind.get_cost() {
+            let removed = safehouse.stash.remove_by_id(id, *count);
+            self.human.inventory.remove_by_id(id, count - removed);
+        }
+
+        safehouse.build(kind);
+        println!("You spend a while putting together a {kind}\n");
+        sleep(1000);
+
+        kind.get_build_duration()
+    }
+
+    /// Gives the safehouse up, leaving the stash behind on the floor
+    fn abandon_safehouse(&mut self, game: &mut Game) {
+        let prompt = collect_with_options(
+            "Abandon your safehouse? Everything built here is lost",
+            &["Yes", "No"]
+        );
+
+        if prompt.unwrap() != 0 {
+            return;
+        }
+
+        let Some(mut safehouse) = game.safehouse.take() else {
+            return;
+        };
+
+        while let Some(item) = safehouse.stash.take(0, u32::MAX) {
+            let item = GroundItem::new(&mut game.rng, item, safehouse.location);
+            game.entities.insert(RegisteredEntity::Item(item));
+        }
+
+        println!("You leave the safehouse behind\n");
+        sleep(1000);
+    }
+}
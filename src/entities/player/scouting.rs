@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use crate::{
+    clock,
+    game::Game,
+    party::CompanionRole,
+    util::sleep,
+    world::LocationId
+};
+use super::Player;
+
+/// Time it takes to climb up somewhere high and take a look around
+const SCOUT_DURATION: Duration = clock::minutes(30);
+/// Locations at least this loud are worth mentioning
+const NOTABLE_NOISE: f32 = 1.0;
+
+impl Player {
+    /// Looks around the current location and every one next to it for hordes.
+    /// A scout in the party can put a number on them
+    pub(super) fn scout(&mut self, game: &mut Game) -> Duration {
+        println!("You find a vantage point and take a look around...\n");
+
+        let here = self.human.current_location;
+        let mut locations = vec![(here, None)];
+        locations.extend(
+            game.world
+                .get_neighbours(here)
+                .into_iter()
+                .map(|(id, distance)| (id, Some(distance)))
+        );
+
+        for (id, distance) in locations {
+            let name = match distance {
+                Some(distance) => format!("{} ({:.1}km)", game.world.location(id), distance),
+                None => String::from("Right here")
+            };
+
+            println!("{}: {}", name, self.get_scouting_report(game, id));
+        }
+
+        println!();
+        sleep(2000);
+
+        SCOUT_DURATION
+    }
+
+    fn get_scouting_report(&self, game: &Game, location: LocationId) -> String {
+        let has_scout = self.party.has_role(CompanionRole::Scout);
+
+        let mut sightings = game.world
+            .hordes_at(location)
+            .map(|horde| {
+                if has_scout {
+                    format!("{} ({} strong)", horde.get_size_formatted(), horde.size)
+                } else {
+                    horde.get_size_formatted().to_string()
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if game.world.get_noise(location) >= NOTABLE_NOISE {
+            sightings.push(String::from("Sounds of commotion"));
+        }
+
+        if sightings.is_empty() {
+            return String::from("Quiet");
+        }
+
+        sightings.join(", ")
+    }
+}
@@ -0,0 +1,544 @@
+use std::time::Duration;
+
+use crate::{
+    clock,
+    combat::Enemy,
+    dialogue::Dialogue,
+    entities::{EntityAttribute, EntityId, GroundItem, Human, RegisteredEntity, RelationshipKind},
+    game::Game,
+    skill_checks::SkillCheck,
+    survivors::{get_attitude, Attitude},
+    util::{collect_with_options, random, random_range, sleep}
+};
+use super::{experience, Player};
+
+/// Time a chat with another survivor takes
+const TALK_DURATION: Duration = clock::minutes(10);
+/// Time haggling over a single trade takes
+const TRADE_DURATION: Duration = clock::minutes(15);
+/// Time it takes to talk somebody into joining up
+const RECRUIT_DURATION: Duration = clock::minutes(10);
+/// Time a tense standoff or a shakedown takes
+const STANDOFF_DURATION: Duration = clock::minutes(5);
+const RECRUIT_CHANCE_PER_TRUST: f32 = 0.005;
+/// Chance of slipping away from a hostile survivor before luck
+const BACK_AWAY_CHANCE: f32 = 0.5;
+const BACK_AWAY_CHANCE_PER_LUCK: f32 = 0.04;
+/// How much a successful haggle takes off prices, and how much a failed one adds
+const HAGGLE_DISCOUNT: f32 = 0.8;
+const HAGGLE_PENALTY: f32 = 1.1;
+/// How much more the player's goods are worth to somebody who bought a lie about them
+const LIE_VALUE_BONUS: f32 = 1.5;
+/// How word of the player's deeds spreads among survivors
+const TRADE_REPUTATION: f32 = 1.0;
+const RECRUIT_REPUTATION: f32 = 2.0;
+const SHAKEDOWN_REPUTATION: f32 = -5.0;
+const ATTACK_REPUTATION: f32 = -15.0;
+
+/// How a standoff with a hostile survivor played out
+enum Standoff {
+    /// They lowered their guard and are willing to talk
+    Calmed,
+    /// The player got away, or they did
+    Parted,
+    Fight
+}
+
+impl Player {
+    /// Lists the other survivors at the current location.
+    /// Returns the in-game time spent dealing with them
+    pub(super) fn meet_survivors(&mut self, game: &mut Game) -> Duration {
+        let location = self.human.current_location;
+        let survivors = game.entities
+            .humans()
+            .filter(|human| human.current_location == location && !self.party.contains(human.id))
+            .map(|human| (human.id, human.get_name_formatted()))
+            .collect::<Vec<_>>();
+
+        if survivors.is_empty() {
+            println!("There is nobody else around\n");
+            sleep(1000);
+            return Duration::ZERO;
+        }
+
+        let mut options = survivors.iter().map(|(_, name)| name.as_str()).collect::<Vec<_>>();
+        options.push("Back");
+
+        let index = collect_with_options("Who would you like to approach?", &options).unwrap();
+        match survivors.get(index) {
+            Some((id, _)) => self.interact_with_survivor(game, *id),
+            None => Duration::ZERO
+        }
+    }
+
+    /// Deals with a single survivor, whose attitude is settled the moment they meet.
+    /// Returns the in-game time spent doing so
+    pub(super) fn interact_with_survivor(&mut self, game: &mut Game, id: EntityId) -> Duration {
+        let Some(survivor) = game.entities.get_human(id) else {
+            return Duration::ZERO;
+        };
+
+        let mut attitude = get_attitude(survivor, self.human.id, self.reputation);
+        let name = survivor.get_name_formatted();
+        let mut elapsed = Duration::ZERO;
+
+        if attitude == Attitude::Hostile {
+            elapsed += STANDOFF_DURATION;
+
+            match self.face_hostile_survivor(game, id) {
+                Standoff::Calmed => attitude = Attitude::Wary,
+                Standoff::Parted => return elapsed,
+                Standoff::Fight => return elapsed + self.fight_survivor(game, id, attitude)
+            }
+        }
+
+        // Prices can be haggled down, and stay that way for as long as they talk
+        let mut markup = attitude.get_price_multiplier();
+
+        loop {
+            let Some(survivor) = game.entities.get_human(id) else {
+                return elapsed;
+            };
+
+            println!(
+                "{} (Age: {}) | {} | Wielding: {}",
+                name,
+                survivor.age,
+                attitude,
+                survivor.weapon.as_ref().map_or("Nothing", |weapon| weapon.name.as_str())
+            );
+
+            if let Some(ties) = get_ties_formatted(game, survivor) {
+                println!("{ties}");
+            }
+
+            let recruit = format!(
+                "[{} {:.0}%] Ask them to join you",
+                SkillCheck::Persuade,
+                self.get_recruit_chance(survivor, attitude) * 100.0
+            );
+            let shakedown = SkillCheck::Intimidate.get_option_formatted("Demand their supplies", &self.human, survivor);
+
+            let prompt = collect_with_options(
+                "What would you like to do?",
+                &["Talk", "Trade", &recruit, &shakedown, "Ignore", "Attack"]
+            );
+
+            match prompt.unwrap() {
+                0 => elapsed += self.talk_to_survivor(game, id, attitude),
+                1 => elapsed += self.trade_with_survivor(game, id, &mut markup),
+                2 => {
+                    elapsed += RECRUIT_DURATION;
+                    if self.recruit_survivor(game, id, attitude) {
+                        return elapsed;
+                    }
+                },
+                3 => {
+                    elapsed += STANDOFF_DURATION;
+                    if !self.shake_down_survivor(game, id) {
+                        return elapsed + self.fight_survivor(game, id, Attitude::Hostile);
+                    }
+                },
+                4 => return elapsed,
+                5 => return elapsed + self.fight_survivor(game, id, attitude),
+                _ => unreachable!()
+            }
+        }
+    }
+
+    /// A hostile survivor squares up to the player, who can fight, run or try to talk their way out
+    fn face_hostile_survivor(&mut self, game: &mut Game, id: EntityId) -> Standoff {
+        let Some(survivor) = game.entities.get_human(id) else {
+            return Standoff::Parted;
+        };
+
+        let first_name = survivor.first_name.clone();
+        println!("{} eyes you with open hostility", survivor.get_name_formatted());
+
+        let checks = [
+            (SkillCheck::Persuade, "Talk them down"),
+            (SkillCheck::Intimidate, "Tell them to back off"),
+            (SkillCheck::Lie, "Claim your group is right around the corner")
+        ];
+
+        let check_options = checks
+            .iter()
+            .map(|(check, text)| check.get_option_formatted(text, &self.human, survivor))
+            .collect::<Vec<_>>();
+
+        let mut options = vec!["Attack", "Back away slowly"];
+        options.extend(check_options.iter().map(String::as_str));
+
+        let index = collect_with_options("What do you do?", &options).unwrap();
+        let check = match index {
+            0 => return Standoff::Fight,
+            1 => {
+                let chance = BACK_AWAY_CHANCE + BACK_AWAY_CHANCE_PER_LUCK * self.human.get_attributes().luck as f32;
+                if random(&mut game.rng) < chance {
+                    println!("You back off before things turn ugly\n");
+                    return Standoff::Parted;
+                }
+
+                println!("{first_name} comes at you!\n");
+                return Standoff::Fight;
+            },
+            i => checks[i - 2].0
+        };
+
+        if !check.roll(&mut game.rng, &self.human, survivor) {
+            match check {
+                SkillCheck::Lie => {
+                    println!("{first_name} doesn't buy it for a second and comes at you!\n");
+                    self.record_interaction(game, id, "Was lied to", -5.0, -10.0);
+                },
+                _ => println!("{first_name} isn't having any of it and comes at you!\n")
+            }
+
+            return Standoff::Fight;
+        }
+
+        match check {
+            SkillCheck::Persuade => {
+                println!("{first_name} slowly lowers their guard\n");
+                self.record_interaction(game, id, "Was talked down from a fight", 10.0, 5.0);
+                Standoff::Calmed
+            },
+            SkillCheck::Intimidate => {
+                println!("{first_name} thinks better of it and backs off\n");
+                self.record_interaction(game, id, "Was scared off", -10.0, 0.0);
+                Standoff::Parted
+            },
+            _ => {
+                println!("{first_name} glances around nervously and slips away\n");
+                Standoff::Parted
+            }
+        }
+    }
+
+    /// Records an interaction on both sides of the relationship with a survivor
+    pub(super) fn record_interaction(&mut self, game: &mut Game, id: EntityId, description: &str, affinity: f32, trust: f32) {
+        self.human
+            .get_relationship_mut(id)
+            .record(game.time, description, affinity, trust);
+
+        if let Some(survivor) = game.entities.get_human_mut(id) {
+            survivor
+                .get_relationship_mut(self.human.id)
+                .record(game.time, description, affinity, trust);
+        }
+    }
+
+    /// Strikes up one of the conversations a survivor with this attitude would have
+    pub(super) fn talk_to_survivor(&mut self, game: &mut Game, id: EntityId, attitude: Attitude) -> Duration {
+        let dialogue = Dialogue::choose(&mut game.rng, attitude);
+        let Some(survivor) = game.entities.get_human_mut(id) else {
+            return Duration::ZERO;
+        };
+
+        let Some(dialogue) = dialogue else {
+            println!("{} has nothing to say to you\n", survivor.first_name);
+            sleep(1000);
+            return Duration::ZERO;
+        };
+
+        let outcome = dialogue.run(&mut self.human, survivor);
+        self.record_interaction(game, id, &dialogue.description, outcome.affinity, outcome.trust);
+
+        for item in outcome.dropped {
+            let item = GroundItem::new(&mut game.rng, item, self.human.current_location);
+            game.entities.insert(RegisteredEntity::Item(item));
+        }
+
+        TALK_DURATION
+    }
+
+    /// Swaps one unit of the survivor's goods for enough of the player's to cover its price.
+    /// The price can be haggled over or the player's goods talked up, once per trade
+    fn trade_with_survivor(&mut self, game: &mut Game, id: EntityId, markup: &mut f32) -> Duration {
+        let Some(survivor) = game.entities.get_human(id) else {
+            return Duration::ZERO;
+        };
+
+        if survivor.inventory.is_empty() {
+            println!("{} has nothing to trade\n", survivor.first_name);
+            sleep(1000);
+            return Duration::ZERO;
+        }
+
+        if self.human.inventory.is_empty() {
+            println!("You have nothing to offer\n");
+            sleep(1000);
+            return Duration::ZERO;
+        }
+
+        let wares = survivor.inventory
+            .items()
+            .iter()
+            .map(|item| format!("{} - {:.0} each", item, item.get_total_value() / item.count as f32 * *markup))
+            .collect::<Vec<_>>();
+
+        let mut options = wares.iter().map(String::as_str).collect::<Vec<_>>();
+        options.push("Back");
+
+        let ware_index = collect_with_options("What would you like?", &options).unwrap();
+        let Some(ware) = survivor.inventory.get(ware_index) else {
+            return Duration::ZERO;
+        };
+
+        let first_name = survivor.first_name.clone();
+        let ware_name = ware.name.clone();
+        let ware_value = ware.get_total_value() / ware.count as f32;
+
+        let Some(offer_index) = self.choose_item("What do you offer in return?") else {
+            return Duration::ZERO;
+        };
+
+        let offer = &self.human.inventory.items()[offer_index];
+        let offer_name = offer.name.clone();
+        let offer_count = offer.count;
+        let mut unit_value = offer.get_total_value() / offer.count as f32;
+
+        if unit_value <= 0.0 {
+            println!("{first_name} has no use for {offer_name}\n");
+            sleep(1000);
+            return Duration::ZERO;
+        }
+
+        let mut bargained = false;
+        let needed = loop {
+            let needed = ((ware_value * *markup / unit_value).ceil() as u32).max(1);
+            if needed > offer_count {
+                println!("{first_name} wants {needed} {offer_name} for that, you only have {offer_count}\n");
+                sleep(1000);
+                return TRADE_DURATION;
+            }
+
+            let Some(survivor) = game.entities.get_human(id) else {
+                return TRADE_DURATION;
+            };
+
+            let haggle = SkillCheck::Barter.get_option_formatted("Haggle", &self.human, survivor);
+            let lie = SkillCheck::Lie.get_option_formatted(&format!("Talk up your {offer_name}"), &self.human, survivor);
+
+            let mut options = vec!["Deal"];
+            if !bargained {
+                options.push(&haggle);
+                options.push(&lie);
+            }
+            options.push("No deal");
+
+            let prompt = collect_with_options(
+                &format!("Trade {} {} for 1 {}?", needed, offer_name, ware_name),
+                &options
+            );
+
+            let check = match (prompt.unwrap(), bargained) {
+                (0, _) => break needed,
+                (1, false) => SkillCheck::Barter,
+                (2, false) => SkillCheck::Lie,
+                _ => return TRADE_DURATION
+            };
+
+            bargained = true;
+            let success = check.roll(&mut game.rng, &self.human, survivor);
+
+            match (check, success) {
+                (SkillCheck::Barter, true) => {
+                    println!("{first_name} grudgingly knocks the price down\n");
+                    *markup *= HAGGLE_DISCOUNT;
+                    self.gain_experience(EntityAttribute::Charisma(experience::PERSUASION_EXPERIENCE));
+                },
+                (SkillCheck::Barter, false) => {
+                    println!("{first_name} digs their heels in and raises the price\n");
+                    *markup *= HAGGLE_PENALTY;
+                    self.record_interaction(game, id, "Haggled too hard", -2.0, 0.0);
+                },
+                (_, true) => {
+                    println!("{first_name} buys your story about the {offer_name}\n");
+                    unit_value *= LIE_VALUE_BONUS;
+                    self.gain_experience(EntityAttribute::Charisma(experience::PERSUASION_EXPERIENCE));
+                },
+                (_, false) => {
+                    println!("{first_name} sees right through you and refuses to trade\n");
+                    self.record_interaction(game, id, "Was lied to", -10.0, -10.0);
+                    sleep(1000);
+                    return TRADE_DURATION;
+                }
+            }
+        };
+
+        let (Some(payment), Some(survivor)) = (
+            self.human.inventory.take(offer_index, needed),
+            game.entities.get_human_mut(id)
+        ) else {
+            return TRADE_DURATION;
+        };
+
+        let Some(ware) = survivor.inventory.take(ware_index, 1) else {
+            return TRADE_DURATION;
+        };
+
+        if let Err(ware) = self.human.pick_up(ware) {
+            println!("{} is too heavy for you to carry\n", ware.name);
+            survivor.inventory.add(*ware);
+            self.human.inventory.add(payment);
+            return TRADE_DURATION;
+        }
+
+        println!("You trade {} {} for {}\n", needed, payment.name, ware_name);
+        survivor.inventory.add(payment);
+
+        self.record_interaction(game, id, "Traded supplies", 2.0, 3.0);
+        self.add_reputation(TRADE_REPUTATION);
+        self.gain_experience(EntityAttribute::Charisma(experience::TRADE_EXPERIENCE));
+        sleep(1000);
+
+        TRADE_DURATION
+    }
+
+    /// The chance of a survivor agreeing to join up. Talking them into it
+    /// is a persuasion check, helped along by how they already feel
+    fn get_recruit_chance(&self, survivor: &Human, attitude: Attitude) -> f32 {
+        let trust = survivor
+            .get_relationship(self.human.id)
+            .map_or(0.0, |relationship| relationship.trust);
+
+        let chance = SkillCheck::Persuade.get_chance(&self.human, survivor)
+            + attitude.get_recruit_chance()
+            + RECRUIT_CHANCE_PER_TRUST * trust;
+
+        chance.min(0.95)
+    }
+
+    /// Asks a survivor to join up. Returns whether they agreed
+    fn recruit_survivor(&mut self, game: &mut Game, id: EntityId, attitude: Attitude) -> bool {
+        let Some(survivor) = game.entities.get_human(id) else {
+            return false;
+        };
+
+        let first_name = survivor.first_name.clone();
+        let chance = self.get_recruit_chance(survivor, attitude);
+
+        if random(&mut game.rng) >= chance {
+            println!("{first_name} would rather go it alone, for now\n");
+            self.record_interaction(game, id, "Turned down an offer to join up", -3.0, 0.0);
+            sleep(1000);
+            return false;
+        }
+
+        println!("{first_name} agrees to stick with you\n");
+        if let Some(survivor) = game.entities.get_human(id) {
+            self.party.add(survivor);
+        }
+
+        self.record_interaction(game, id, "Joined up", 10.0, 10.0);
+        self.add_reputation(RECRUIT_REPUTATION);
+        self.gain_experience(EntityAttribute::Charisma(experience::PERSUASION_EXPERIENCE));
+        sleep(1000);
+
+        true
+    }
+
+    /// Threatens a survivor into handing over some of their supplies.
+    /// Returns false if they would rather fight
+    fn shake_down_survivor(&mut self, game: &mut Game, id: EntityId) -> bool {
+        let Some(survivor) = game.entities.get_human(id) else {
+            return true;
+        };
+
+        let first_name = survivor.first_name.clone();
+        let success = SkillCheck::Intimidate.roll(&mut game.rng, &self.human, survivor);
+        self.add_reputation(SHAKEDOWN_REPUTATION);
+
+        if !success {
+            println!("{first_name} won't be pushed around!\n");
+            return false;
+        }
+
+        self.record_interaction(game, id, "Was shaken down", -15.0, -10.0);
+
+        let Some(survivor) = game.entities.get_human_mut(id) else {
+            return true;
+        };
+
+        if survivor.inventory.is_empty() {
+            println!("{first_name} turns out their pockets. They have nothing\n");
+            sleep(1000);
+            return true;
+        }
+
+        let index = random_range(&mut game.rng, 0.0..survivor.inventory.len() as f32) as usize;
+        let Some(item) = survivor.inventory.take(index.min(survivor.inventory.len() - 1), u32::MAX) else {
+            return true;
+        };
+
+        println!("{first_name} hands over {item}");
+        if let Err(item) = self.human.pick_up(item) {
+            println!("{} is too heavy to carry, you leave it on the ground", item.name);
+            let item = GroundItem::new(&mut game.rng, *item, self.human.current_location);
+            game.entities.insert(RegisteredEntity::Item(item));
+        }
+
+        println!();
+        sleep(1000);
+
+        true
+    }
+
+    /// Fights a survivor. Whatever they carried is left on the ground if they go down.
+    /// Returns the in-game time the fight took
+    fn fight_survivor(&mut self, game: &mut Game, id: EntityId, attitude: Attitude) -> Duration {
+        let Some(RegisteredEntity::Human(survivor)) = game.entities.remove(id) else {
+            return Duration::ZERO;
+        };
+
+        // Defending yourself is one thing, attacking the peaceful is another
+        if attitude != Attitude::Hostile {
+            self.add_reputation(ATTACK_REPUTATION);
+        }
+
+        let enemies = vec![Enemy::Human(Box::new(survivor))];
+        let report = self.fight(game, enemies);
+
+        for enemy in report.survivors {
+            if let Enemy::Human(survivor) = enemy {
+                game.entities.insert(RegisteredEntity::Human(*survivor));
+                self.record_interaction(game, id, "Came to blows", -40.0, -30.0);
+            }
+        }
+
+        for enemy in report.fallen {
+            let Enemy::Human(mut survivor) = enemy else {
+                continue;
+            };
+
+            self.human.remove_relationship(survivor.id);
+            survivor.unequip_weapon();
+
+            let location = self.human.current_location;
+            while let Some(item) = survivor.inventory.take(0, u32::MAX) {
+                let item = GroundItem::new(&mut game.rng, item, location);
+                game.entities.insert(RegisteredEntity::Item(item));
+            }
+
+            println!("{} dropped everything they carried\n", survivor.first_name);
+        }
+
+        report.elapsed
+    }
+}
+
+/// Who a survivor knew from before among the people around them
+fn get_ties_formatted(game: &Game, survivor: &Human) -> Option<String> {
+    let ties = survivor.relationships
+        .iter()
+        .filter(|relationship| relationship.kind != RelationshipKind::Stranger)
+        .filter_map(|relationship| {
+            let other = game.entities.get_human(relationship.target)?;
+            (other.current_location == survivor.current_location)
+                .then(|| format!("{} ({})", other.get_name_formatted(), relationship.kind))
+        })
+        .collect::<Vec<_>>();
+
+    (!ties.is_empty()).then(|| format!("With: {}", ties.join(", ")))
+}
@@ -0,0 +1,129 @@
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+
+use crate::world::LocationId;
+use super::{Entity, EntityId, EntityType, GroundItem, Human, Zombie};
+
+/// Any entity that can be stored in the registry
+#[derive(Serialize, Deserialize, Debug)]
+pub enum RegisteredEntity {
+    Human(Human),
+    Zombie(Zombie),
+    Item(GroundItem)
+}
+
+impl RegisteredEntity {
+    fn as_entity(&self) -> &dyn Entity {
+        match self {
+            RegisteredEntity::Human(human) => human,
+            RegisteredEntity::Zombie(zombie) => zombie,
+            RegisteredEntity::Item(item) => item
+        }
+    }
+
+    pub fn get_id(&self) -> EntityId {
+        self.as_entity().get_id()
+    }
+
+    pub fn get_entity_type(&self) -> EntityType {
+        self.as_entity().get_entity_type()
+    }
+}
+
+/// Every entity in the world besides the player, keyed by id.
+/// Entities are kept ordered by id so iterating them is deterministic
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct EntityRegistry {
+    entities: BTreeMap<EntityId, RegisteredEntity>,
+}
+
+impl EntityRegistry {
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    pub fn contains(&self, id: EntityId) -> bool {
+        self.entities.contains_key(&id)
+    }
+
+    /// Adds an entity, returning the one it replaced if the id was already taken
+    pub fn insert(&mut self, entity: RegisteredEntity) -> Option<RegisteredEntity> {
+        self.entities.insert(entity.get_id(), entity)
+    }
+
+    pub fn remove(&mut self, id: EntityId) -> Option<RegisteredEntity> {
+        self.entities.remove(&id)
+    }
+
+    pub fn get(&self, id: EntityId) -> Option<&RegisteredEntity> {
+        self.entities.get(&id)
+    }
+
+    pub fn get_mut(&mut self, id: EntityId) -> Option<&mut RegisteredEntity> {
+        self.entities.get_mut(&id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &RegisteredEntity> {
+        self.entities.values()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut RegisteredEntity> {
+        self.entities.values_mut()
+    }
+
+    pub fn iter_type(&self, entity_type: EntityType) -> impl Iterator<Item = &RegisteredEntity> {
+        self.iter().filter(move |entity| entity.get_entity_type() == entity_type)
+    }
+
+    /* Typed accessors */
+    pub fn get_human(&self, id: EntityId) -> Option<&Human> {
+        match self.get(id) {
+            Some(RegisteredEntity::Human(human)) => Some(human),
+            _ => None
+        }
+    }
+
+    pub fn get_human_mut(&mut self, id: EntityId) -> Option<&mut Human> {
+        match self.get_mut(id) {
+            Some(RegisteredEntity::Human(human)) => Some(human),
+            _ => None
+        }
+    }
+
+    pub fn humans(&self) -> impl Iterator<Item = &Human> {
+        self.iter().filter_map(|entity| match entity {
+            RegisteredEntity::Human(human) => Some(human),
+            _ => None
+        })
+    }
+
+    pub fn humans_mut(&mut self) -> impl Iterator<Item = &mut Human> {
+        self.iter_mut().filter_map(|entity| match entity {
+            RegisteredEntity::Human(human) => Some(human),
+            _ => None
+        })
+    }
+
+    pub fn zombies(&self) -> impl Iterator<Item = &Zombie> {
+        self.iter().filter_map(|entity| match entity {
+            RegisteredEntity::Zombie(zombie) => Some(zombie),
+            _ => None
+        })
+    }
+
+    pub fn zombies_at(&self, location: LocationId) -> impl Iterator<Item = &Zombie> {
+        self.zombies().filter(move |zombie| zombie.location == location)
+    }
+
+    /// Items lying on the ground at a location
+    pub fn items_at(&self, location: LocationId) -> impl Iterator<Item = &GroundItem> {
+        self.iter().filter_map(move |entity| match entity {
+            RegisteredEntity::Item(item) if item.location == location => Some(item),
+            _ => None
+        })
+    }
+}
@@ -1,8 +1,9 @@
 use serde::{Serialize, Deserialize};
+use std::fmt;
 
-use crate::util::random_range;
+use crate::{rng::GameRng, util::random_range};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
 pub enum EntityAttribute {
     Intelligence(u8),
     Strength(u8),
@@ -11,7 +12,53 @@ pub enum EntityAttribute {
     Combat(u8)
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+impl EntityAttribute {
+    /// Every attribute, holding nothing
+    pub const ALL: [EntityAttribute; 5] = [
+        EntityAttribute::Intelligence(0),
+        EntityAttribute::Strength(0),
+        EntityAttribute::Charisma(0),
+        EntityAttribute::Luck(0),
+        EntityAttribute::Combat(0)
+    ];
+
+    pub fn get_value(&self) -> u8 {
+        match self {
+            EntityAttribute::Intelligence(value)
+            | EntityAttribute::Strength(value)
+            | EntityAttribute::Charisma(value)
+            | EntityAttribute::Luck(value)
+            | EntityAttribute::Combat(value) => *value
+        }
+    }
+
+    /// The same attribute holding a different value
+    pub fn with_value(&self, value: u8) -> Self {
+        match self {
+            EntityAttribute::Intelligence(_) => EntityAttribute::Intelligence(value),
+            EntityAttribute::Strength(_) => EntityAttribute::Strength(value),
+            EntityAttribute::Charisma(_) => EntityAttribute::Charisma(value),
+            EntityAttribute::Luck(_) => EntityAttribute::Luck(value),
+            EntityAttribute::Combat(_) => EntityAttribute::Combat(value)
+        }
+    }
+}
+
+impl fmt::Display for EntityAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EntityAttribute::Intelligence(_) => "Intelligence",
+            EntityAttribute::Strength(_) => "Strength",
+            EntityAttribute::Charisma(_) => "Charisma",
+            EntityAttribute::Luck(_) => "Luck",
+            EntityAttribute::Combat(_) => "Combat"
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct EntityAttributes {
     pub intelligence: u8,
     pub strength: u8,
@@ -20,22 +67,13 @@ pub struct EntityAttributes {
     pub combat: u8,
 }
 
-impl Default for EntityAttributes {
-    fn default() -> Self {
-        Self {
-            intelligence: Default::default(),
-            strength: Default::default(),
-            charisma: Default::default(),
-            luck: Default::default(),
-            combat: Default::default()
-        }
-    }
-}
-
 impl EntityAttributes {
-    pub fn initial_stats() -> Self {
-        let get_initial_stat = || -> u8 {
-            random_range(0.0..=5.0) as u8
+    /// No amount of practice takes an attribute past this
+    pub const MAX_ATTRIBUTE: u8 = 10;
+
+    pub fn initial_stats(rng: &mut GameRng) -> Self {
+        let mut get_initial_stat = || -> u8 {
+            random_range(rng, 0.0..=5.0) as u8
         };
 
         Self {
@@ -89,23 +127,14 @@ impl EntityAttributes {
         }
     }
 
+    /// Raises an attribute, never past `MAX_ATTRIBUTE`
     pub fn add_attribute(&mut self, attr: EntityAttribute) {
-        match attr {
-            EntityAttribute::Intelligence(value) => self.intelligence.checked_add(value),
-            EntityAttribute::Strength(value) => self.strength.checked_add(value),
-            EntityAttribute::Charisma(value) => self.charisma.checked_add(value),
-            EntityAttribute::Luck(value) => self.luck.checked_add(value),
-            EntityAttribute::Combat(value) => self.combat.checked_add(value),
-        };
+        let value = self.get_attribute_mut(attr);
+        *value = value.saturating_add(attr.get_value()).min(Self::MAX_ATTRIBUTE);
     }
 
     pub fn sub_attribute(&mut self, attr: EntityAttribute) {
-        match attr {
-            EntityAttribute::Intelligence(value) => self.intelligence.checked_sub(value),
-            EntityAttribute::Strength(value) => self.strength.checked_sub(value),
-            EntityAttribute::Charisma(value) => self.charisma.checked_sub(value),
-            EntityAttribute::Luck(value) => self.luck.checked_sub(value),
-            EntityAttribute::Combat(value) => self.combat.checked_sub(value),
-        };
+        let value = self.get_attribute_mut(attr);
+        *value = value.saturating_sub(attr.get_value());
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,250 @@
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+use crate::{rng::GameRng, util::random};
+use super::{EntityAttribute, EntityStat};
+
+/// A disease or injury that can afflict a human. The zombie virus is tracked separately, see `Infection`
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConditionKind {
+    /// A wound gone bad. Spreads fever until treated with antibiotics
+    Infection,
+    /// Caught from dirty water, drains fluids fast
+    Dysentery,
+    Flu,
+    Cut,
+    Fracture,
+    Bleeding
+}
+
+impl ConditionKind {
+    /// Change in severity per hour left alone. Negative values heal on their own
+    fn get_progression_per_hour(&self) -> f32 {
+        match self {
+            ConditionKind::Infection => 0.02,
+            ConditionKind::Dysentery => -0.015,
+            ConditionKind::Flu => -0.01,
+            ConditionKind::Cut => -0.02,
+            ConditionKind::Fracture => -0.004,
+            ConditionKind::Bleeding => -0.05
+        }
+    }
+
+    /// Extra healing per hour of rest. Some things rest alone can not fix
+    fn get_rest_healing_per_hour(&self) -> f32 {
+        match self {
+            ConditionKind::Infection | ConditionKind::Bleeding => 0.0,
+            ConditionKind::Fracture => 0.01,
+            _ => 0.03
+        }
+    }
+
+    pub fn is_injury(&self) -> bool {
+        matches!(self, ConditionKind::Cut | ConditionKind::Fracture | ConditionKind::Bleeding)
+    }
+
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            ConditionKind::Infection => "Fever and weakness, worsens without antibiotics",
+            ConditionKind::Dysentery => "Thirst and hunger build up faster",
+            ConditionKind::Flu => "Drains energy and dulls the wits",
+            ConditionKind::Cut => "Slowly bleeds, may get infected",
+            ConditionKind::Fracture => "Weakens strength and combat until set",
+            ConditionKind::Bleeding => "Quickly drains health until bandaged"
+        }
+    }
+}
+
+impl fmt::Display for ConditionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            ConditionKind::Infection => "Infection",
+            ConditionKind::Dysentery => "Dysentery",
+            ConditionKind::Flu => "Flu",
+            ConditionKind::Cut => "Cut",
+            ConditionKind::Fracture => "Fracture",
+            ConditionKind::Bleeding => "Bleeding"
+        };
+
+        write!(f, "{kind}")
+    }
+}
+
+/// A condition and how bad it is, from 0 (gone) to 1 (as bad as it gets)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct Condition {
+    pub kind: ConditionKind,
+    pub severity: f32,
+}
+
+impl Condition {
+    pub const MAX_SEVERITY: f32 = 1.0;
+    /// Severity taken off by a single dose of the right medicine
+    pub const TREATMENT_SEVERITY: f32 = 0.6;
+    /// Hits dealing at least this much damage may break bones
+    const FRACTURE_DAMAGE: f32 = 12.0;
+    const FRACTURE_CHANCE: f32 = 0.25;
+    /// Chance per point of damage of a hit leaving a cut or a bleeding wound
+    const CUT_CHANCE_PER_DAMAGE: f32 = 0.04;
+    const BLEEDING_CHANCE_PER_DAMAGE: f32 = 0.015;
+    /// Chance per hour of a cut getting infected at full severity
+    const INFECTION_CHANCE_PER_HOUR: f32 = 0.05;
+    /// Chance per hour of catching the flu while exhausted
+    const FLU_CHANCE_PER_HOUR: f32 = 0.02;
+
+    pub fn new(kind: ConditionKind, severity: f32) -> Self {
+        Self { kind, severity: severity.clamp(0.0, Self::MAX_SEVERITY) }
+    }
+
+    /// Whether it has healed completely
+    pub fn is_cured(&self) -> bool {
+        self.severity <= 0.0
+    }
+
+    /// Lets the condition run its course
+    pub fn progress(&mut self, hours: f32) {
+        let change = self.kind.get_progression_per_hour() * hours;
+        self.severity = (self.severity + change).clamp(0.0, Self::MAX_SEVERITY);
+    }
+
+    /// Heals on top of the regular progression while resting
+    pub fn rest(&mut self, hours: f32) {
+        self.severity = (self.severity - self.kind.get_rest_healing_per_hour() * hours).max(0.0);
+    }
+
+    pub fn treat(&mut self) {
+        self.severity = (self.severity - Self::TREATMENT_SEVERITY).max(0.0);
+    }
+
+    /// What the condition does to the stats of whoever has it over the given hours
+    pub fn get_stat_effects(&self, hours: f32) -> Vec<EntityStat> {
+        let amount = self.severity * hours;
+
+        match self.kind {
+            ConditionKind::Infection => vec![EntityStat::Health(-1.5 * amount), EntityStat::Thirst(1.0 * amount)],
+            ConditionKind::Dysentery => vec![EntityStat::Thirst(4.0 * amount), EntityStat::Hunger(1.0 * amount)],
+            ConditionKind::Flu => vec![EntityStat::Energy(-0.03 * amount), EntityStat::Health(-0.5 * amount)],
+            ConditionKind::Cut => vec![EntityStat::Health(-0.5 * amount)],
+            ConditionKind::Fracture => vec![EntityStat::Energy(-0.01 * amount)],
+            ConditionKind::Bleeding => vec![EntityStat::Health(-6.0 * amount)]
+        }
+    }
+
+    /// Points taken off attributes while the condition lasts
+    pub fn get_attribute_penalties(&self) -> Vec<EntityAttribute> {
+        let penalty = |points: f32| (points * self.severity).ceil() as u8;
+
+        match self.kind {
+            ConditionKind::Infection => vec![EntityAttribute::Strength(penalty(2.0))],
+            ConditionKind::Flu => vec![EntityAttribute::Intelligence(penalty(2.0)), EntityAttribute::Charisma(penalty(1.0))],
+            ConditionKind::Fracture => vec![EntityAttribute::Strength(penalty(3.0)), EntityAttribute::Combat(penalty(2.0))],
+            _ => Vec::new()
+        }
+    }
+
+    pub fn get_severity_formatted(&self) -> &'static str {
+        match self.severity {
+            s if s < 0.35 => "Mild",
+            s if s < 0.7 => "Moderate",
+            _ => "Severe"
+        }
+    }
+
+    pub fn get_formatted(&self) -> String {
+        format!("{} ({}) - {}", self.kind, self.get_severity_formatted(), self.kind.get_description())
+    }
+
+    /// Rolls whether a hit of the given damage leaves an injury behind
+    pub fn roll_injury(rng: &mut GameRng, damage: f32) -> Option<Self> {
+        let roll = random(rng);
+
+        if damage >= Self::FRACTURE_DAMAGE && roll < Self::FRACTURE_CHANCE {
+            return Some(Self::new(ConditionKind::Fracture, 0.6));
+        }
+
+        if random(rng) < Self::BLEEDING_CHANCE_PER_DAMAGE * damage {
+            return Some(Self::new(ConditionKind::Bleeding, 0.5));
+        }
+
+        if random(rng) < Self::CUT_CHANCE_PER_DAMAGE * damage {
+            return Some(Self::new(ConditionKind::Cut, 0.4));
+        }
+
+        None
+    }
+
+    /// Rolls whether an untreated cut festers over the given hours
+    pub fn roll_infection(&self, rng: &mut GameRng, hours: f32) -> bool {
+        matches!(self.kind, ConditionKind::Cut | ConditionKind::Bleeding)
+            && random(rng) < Self::INFECTION_CHANCE_PER_HOUR * self.severity * hours
+    }
+
+    /// Rolls whether somebody worn out catches the flu over the given hours
+    pub fn roll_flu(rng: &mut GameRng, hours: f32) -> bool {
+        random(rng) < Self::FLU_CHANCE_PER_HOUR * hours
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.kind, self.get_severity_formatted())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::Human;
+
+    #[test]
+    fn untreated_infection_worsens() {
+        let mut condition = Condition::new(ConditionKind::Infection, 0.5);
+        condition.progress(10.0);
+
+        assert!(condition.severity > 0.5);
+    }
+
+    #[test]
+    fn progress_is_capped() {
+        let mut condition = Condition::new(ConditionKind::Infection, 0.9);
+        condition.progress(100.0);
+
+        assert_eq!(condition.severity, Condition::MAX_SEVERITY);
+    }
+
+    #[test]
+    fn cut_heals_on_its_own() {
+        let mut condition = Condition::new(ConditionKind::Cut, 0.4);
+        condition.progress(100.0);
+
+        assert!(condition.is_cured());
+        assert_eq!(condition.severity, 0.0);
+    }
+
+    #[test]
+    fn rest_does_nothing_for_bleeding() {
+        let mut condition = Condition::new(ConditionKind::Bleeding, 0.5);
+        condition.rest(8.0);
+
+        assert_eq!(condition.severity, 0.5);
+    }
+
+    #[test]
+    fn treatment_never_goes_below_zero() {
+        let mut condition = Condition::new(ConditionKind::Infection, 0.3);
+        condition.treat();
+
+        assert!(condition.is_cured());
+        assert_eq!(condition.severity, 0.0);
+    }
+
+    #[test]
+    fn stacking_is_capped() {
+        let mut human = Human::new(&mut GameRng::new(1));
+        human.add_condition(Condition::new(ConditionKind::Cut, 0.7));
+        human.add_condition(Condition::new(ConditionKind::Cut, 0.7));
+
+        assert_eq!(human.conditions.len(), 1);
+        assert_eq!(human.conditions[0].severity, Condition::MAX_SEVERITY);
+    }
+}
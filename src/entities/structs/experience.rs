@@ -0,0 +1,137 @@
+use serde::{Serialize, Deserialize};
+
+use super::{EntityAttribute, EntityAttributes};
+
+/// Experience built up towards the next point of every attribute, earned by putting them to use
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Experience {
+    intelligence: u32,
+    strength: u32,
+    charisma: u32,
+    luck: u32,
+    combat: u32,
+}
+
+impl Experience {
+    /// Experience needed to raise an attribute from nothing
+    const BASE_EXPERIENCE: f32 = 10.0;
+    /// How much more every point costs than the one before it
+    const EXPERIENCE_GROWTH: f32 = 1.5;
+
+    pub fn get(&self, attr: EntityAttribute) -> u32 {
+        match attr {
+            EntityAttribute::Intelligence(_) => self.intelligence,
+            EntityAttribute::Strength(_) => self.strength,
+            EntityAttribute::Charisma(_) => self.charisma,
+            EntityAttribute::Luck(_) => self.luck,
+            EntityAttribute::Combat(_) => self.combat,
+        }
+    }
+
+    fn get_mut(&mut self, attr: EntityAttribute) -> &mut u32 {
+        match attr {
+            EntityAttribute::Intelligence(_) => &mut self.intelligence,
+            EntityAttribute::Strength(_) => &mut self.strength,
+            EntityAttribute::Charisma(_) => &mut self.charisma,
+            EntityAttribute::Luck(_) => &mut self.luck,
+            EntityAttribute::Combat(_) => &mut self.combat,
+        }
+    }
+
+    /// Experience needed to raise an attribute past the given value
+    pub fn get_required(value: u8) -> u32 {
+        (Self::BASE_EXPERIENCE * Self::EXPERIENCE_GROWTH.powi(value as i32)).round() as u32
+    }
+
+    /// Adds the experience held by `attr` to that attribute, raising it for every
+    /// threshold crossed. Returns how many points it went up
+    pub fn gain(&mut self, attributes: &mut EntityAttributes, attr: EntityAttribute) -> u8 {
+        let experience = self.get_mut(attr);
+        *experience += attr.get_value() as u32;
+
+        let mut gained = 0;
+        loop {
+            let value = *attributes.get_attribute(attr);
+
+            // Nothing left to learn
+            if value >= EntityAttributes::MAX_ATTRIBUTE {
+                *experience = 0;
+                break;
+            }
+
+            let required = Self::get_required(value);
+            if *experience < required {
+                break;
+            }
+
+            *experience -= required;
+            attributes.add_attribute(attr.with_value(1));
+            gained += 1;
+        }
+
+        gained
+    }
+
+    /// How close an attribute is to its next point
+    pub fn get_progress_formatted(&self, attributes: &EntityAttributes, attr: EntityAttribute) -> String {
+        let value = *attributes.get_attribute(attr);
+
+        if value >= EntityAttributes::MAX_ATTRIBUTE {
+            return String::from("Mastered");
+        }
+
+        format!("{}/{} XP", self.get(attr), Self::get_required(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn required_grows_with_value() {
+        assert_eq!(Experience::get_required(0), 10);
+        assert_eq!(Experience::get_required(1), 15);
+        assert_eq!(Experience::get_required(2), 23);
+    }
+
+    #[test]
+    fn gain_below_threshold_only_adds_experience() {
+        let mut experience = Experience::default();
+        let mut attributes = EntityAttributes::default();
+
+        assert_eq!(experience.gain(&mut attributes, EntityAttribute::Luck(9)), 0);
+        assert_eq!(attributes.luck, 0);
+        assert_eq!(experience.get(EntityAttribute::Luck(0)), 9);
+    }
+
+    #[test]
+    fn large_gain_levels_up_several_times_and_keeps_the_rest() {
+        let mut experience = Experience::default();
+        let mut attributes = EntityAttributes::default();
+
+        // 10 + 15 + 23 to go from 0 to 3, with 5 left over
+        let gained = experience.gain(&mut attributes, EntityAttribute::Strength(53));
+
+        assert_eq!(gained, 3);
+        assert_eq!(attributes.strength, 3);
+        assert_eq!(experience.get(EntityAttribute::Strength(0)), 5);
+    }
+
+    #[test]
+    fn gain_stops_at_the_cap() {
+        let mut experience = Experience { combat: Experience::get_required(9) - 5, ..Default::default() };
+        let mut attributes = EntityAttributes::default();
+        attributes.set_attribute(EntityAttribute::Combat(EntityAttributes::MAX_ATTRIBUTE - 1));
+
+        let gained = experience.gain(&mut attributes, EntityAttribute::Combat(50));
+
+        assert_eq!(gained, 1);
+        assert_eq!(attributes.combat, EntityAttributes::MAX_ATTRIBUTE);
+        assert_eq!(experience.get(EntityAttribute::Combat(0)), 0);
+
+        assert_eq!(experience.gain(&mut attributes, EntityAttribute::Combat(50)), 0);
+        assert_eq!(attributes.combat, EntityAttributes::MAX_ATTRIBUTE);
+        assert_eq!(experience.get(EntityAttribute::Combat(0)), 0);
+    }
+}
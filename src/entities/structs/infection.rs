@@ -0,0 +1,59 @@
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+/// The zombie virus working its way through a bitten human
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct Infection {
+    /// From 0 (freshly bitten) to 1 (turned)
+    pub progress: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InfectionStage {
+    Early,
+    Advanced,
+    Terminal
+}
+
+impl fmt::Display for InfectionStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stage = match self {
+            InfectionStage::Early => "Early",
+            InfectionStage::Advanced => "Advanced",
+            InfectionStage::Terminal => "Terminal"
+        };
+
+        write!(f, "{stage}")
+    }
+}
+
+impl Default for Infection {
+    fn default() -> Self {
+        Self { progress: 0.0 }
+    }
+}
+
+impl Infection {
+    /// In-game hours between the bite and turning
+    pub const HOURS_TO_TURN: f32 = 48.0;
+    /// Health lost per hour once the infection is advanced
+    pub const DAMAGE_PER_HOUR: f32 = 1.0;
+    /// Extra thirst per hour from the fever
+    pub const THIRST_PER_HOUR: f32 = 1.5;
+
+    pub fn progress(&mut self, hours: f32) {
+        self.progress = (self.progress + hours / Self::HOURS_TO_TURN).min(1.0);
+    }
+
+    pub fn get_stage(&self) -> InfectionStage {
+        match self.progress {
+            p if p < 0.4 => InfectionStage::Early,
+            p if p < 0.8 => InfectionStage::Advanced,
+            _ => InfectionStage::Terminal
+        }
+    }
+
+    pub fn has_turned(&self) -> bool {
+        self.progress >= 1.0
+    }
+}
@@ -1,4 +1,10 @@
 mod attributes;
+mod condition;
+mod experience;
+mod infection;
+mod mood;
+mod perk;
+mod relationship;
 mod stats;
 
 pub use attributes::{
@@ -6,7 +12,33 @@ pub use attributes::{
     EntityAttributes
 };
 
+pub use condition::{
+    Condition,
+    ConditionKind
+};
+
+pub use experience::Experience;
+
+pub use infection::{
+    Infection,
+    InfectionStage
+};
+
+pub use mood::{
+    Emotion,
+    MoodEffects
+};
+
+pub use perk::Perk;
+
+pub use relationship::{
+    Interaction,
+    Relationship,
+    RelationshipKind
+};
+
 pub use stats::{
     EntityStat,
-    EntityStats
+    EntityStats,
+    StatDrain
 };
\ No newline at end of file
@@ -0,0 +1,123 @@
+use serde::{Serialize, Deserialize};
+use std::{fmt, mem};
+
+/// A feeling and how strongly it is felt, from 0 to 1
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub enum Emotion {
+    Happinness(f32),
+    Anger(f32),
+    Surprise(f32),
+    Excitement(f32),
+    Fear(f32),
+    Disgust(f32),
+    Neutral
+}
+
+impl Emotion {
+    pub const MAX_INTENSITY: f32 = 1.0;
+
+    pub fn get_intensity(&self) -> f32 {
+        match self {
+            Emotion::Happinness(value)
+            | Emotion::Anger(value)
+            | Emotion::Surprise(value)
+            | Emotion::Excitement(value)
+            | Emotion::Fear(value)
+            | Emotion::Disgust(value) => *value,
+            Emotion::Neutral => 0.0
+        }
+    }
+
+    /// The same emotion felt with a different intensity
+    pub fn with_intensity(&self, intensity: f32) -> Self {
+        let intensity = intensity.clamp(0.0, Self::MAX_INTENSITY);
+
+        match self {
+            Emotion::Happinness(_) => Emotion::Happinness(intensity),
+            Emotion::Anger(_) => Emotion::Anger(intensity),
+            Emotion::Surprise(_) => Emotion::Surprise(intensity),
+            Emotion::Excitement(_) => Emotion::Excitement(intensity),
+            Emotion::Fear(_) => Emotion::Fear(intensity),
+            Emotion::Disgust(_) => Emotion::Disgust(intensity),
+            Emotion::Neutral => Emotion::Neutral
+        }
+    }
+
+    /// Whether both are the same emotion, however strongly they are felt
+    pub fn is_same_kind(&self, other: &Emotion) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+
+impl fmt::Display for Emotion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let emotion = match self {
+            Emotion::Happinness(_) => "Happy",
+            Emotion::Anger(_) => "Angry",
+            Emotion::Surprise(_) => "Surprised",
+            Emotion::Excitement(_) => "Excited",
+            Emotion::Fear(_) => "Afraid",
+            Emotion::Disgust(_) => "Disgusted",
+            Emotion::Neutral => "Calm"
+        };
+
+        write!(f, "{emotion}")
+    }
+}
+
+/// How the strongest emotion somebody feels changes what they do
+#[derive(Debug, Clone, Copy)]
+pub struct MoodEffects {
+    /// Multiplier on the chance of landing a hit
+    pub accuracy: f32,
+    /// Multiplier on the damage dealt
+    pub damage: f32,
+    /// Points of charisma gained or lost
+    pub charisma: f32,
+    /// Multiplier on how fast health and energy come back
+    pub recovery: f32,
+}
+
+impl Default for MoodEffects {
+    fn default() -> Self {
+        Self {
+            accuracy: 1.0,
+            damage: 1.0,
+            charisma: 0.0,
+            recovery: 1.0,
+        }
+    }
+}
+
+impl MoodEffects {
+    const FEAR_ACCURACY_PENALTY: f32 = 0.3;
+    const ANGER_DAMAGE_BONUS: f32 = 0.3;
+    const ANGER_CHARISMA_PENALTY: f32 = 2.0;
+    const DISGUST_CHARISMA_PENALTY: f32 = 1.0;
+    const HAPPINESS_RECOVERY_BONUS: f32 = 1.0;
+
+    pub fn from_emotion(emotion: &Emotion) -> Self {
+        let intensity = emotion.get_intensity();
+
+        match emotion {
+            Emotion::Fear(_) => Self {
+                accuracy: 1.0 - Self::FEAR_ACCURACY_PENALTY * intensity,
+                ..Self::default()
+            },
+            Emotion::Anger(_) => Self {
+                damage: 1.0 + Self::ANGER_DAMAGE_BONUS * intensity,
+                charisma: -Self::ANGER_CHARISMA_PENALTY * intensity,
+                ..Self::default()
+            },
+            Emotion::Disgust(_) => Self {
+                charisma: -Self::DISGUST_CHARISMA_PENALTY * intensity,
+                ..Self::default()
+            },
+            Emotion::Happinness(_) => Self {
+                recovery: 1.0 + Self::HAPPINESS_RECOVERY_BONUS * intensity,
+                ..Self::default()
+            },
+            _ => Self::default()
+        }
+    }
+}
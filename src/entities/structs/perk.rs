@@ -0,0 +1,94 @@
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+use super::EntityAttribute;
+
+/// A passive trait of a human. Some come with the kind of person they were before
+/// the outbreak, the rest are picked up by getting good enough at something
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Perk {
+    /// Turns up loot in places others have already picked over
+    ScavengersEye,
+    /// Better odds of talking people round, and things to say others would not think of
+    SilverTongue,
+    /// Hits harder in a fight
+    Brawler,
+    /// Can carry more without slowing down
+    PackMule,
+    /// Learns faster from everything they do
+    QuickStudy,
+    /// Needs less sleep
+    Insomniac,
+    /// Goes longer without food and water
+    IronStomach
+}
+
+impl Perk {
+    pub const ALL: [Perk; 7] = [
+        Perk::ScavengersEye,
+        Perk::SilverTongue,
+        Perk::Brawler,
+        Perk::PackMule,
+        Perk::QuickStudy,
+        Perk::Insomniac,
+        Perk::IronStomach
+    ];
+
+    /// Richness added to a location when rolling its loot
+    pub const SCAVENGERS_EYE_RICHNESS: f32 = 0.25;
+    /// Chance added to persuading, bartering and lying
+    pub const SILVER_TONGUE_CHANCE: f32 = 0.1;
+    pub const BRAWLER_DAMAGE_MULTIPLIER: f32 = 1.25;
+    /// Extra kilograms of carry capacity
+    pub const PACK_MULE_CARRY_CAPACITY: f32 = 8.0;
+    pub const QUICK_STUDY_EXPERIENCE_MULTIPLIER: f32 = 1.5;
+    /// Share of the regular energy drain that is spared
+    pub const INSOMNIAC_ENERGY_SAVING: f32 = 0.3;
+    /// Share of the regular hunger and thirst that is spared
+    pub const IRON_STOMACH_SAVING: f32 = 0.25;
+
+    /// The attribute value that unlocks this perk. Perks that only come
+    /// with a background have none
+    pub fn get_unlock(&self) -> Option<EntityAttribute> {
+        match self {
+            Perk::ScavengersEye => Some(EntityAttribute::Luck(8)),
+            Perk::SilverTongue => Some(EntityAttribute::Charisma(8)),
+            Perk::Brawler => Some(EntityAttribute::Combat(8)),
+            Perk::PackMule => Some(EntityAttribute::Strength(7)),
+            Perk::QuickStudy => Some(EntityAttribute::Intelligence(7)),
+            Perk::Insomniac | Perk::IronStomach => None
+        }
+    }
+
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            Perk::ScavengersEye => "Finds more in places that have been picked over",
+            Perk::SilverTongue => "Better at persuading, bartering and lying",
+            Perk::Brawler => "Deals more damage in a fight",
+            Perk::PackMule => "Carries more before becoming encumbered",
+            Perk::QuickStudy => "Gains experience faster",
+            Perk::Insomniac => "Gets tired slower",
+            Perk::IronStomach => "Gets hungry and thirsty slower"
+        }
+    }
+
+    pub fn get_formatted(&self) -> String {
+        format!("{} - {}", self, self.get_description())
+    }
+}
+
+impl fmt::Display for Perk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let perk = match self {
+            Perk::ScavengersEye => "Scavenger's Eye",
+            Perk::SilverTongue => "Silver Tongue",
+            Perk::Brawler => "Brawler",
+            Perk::PackMule => "Pack Mule",
+            Perk::QuickStudy => "Quick Study",
+            Perk::Insomniac => "Insomniac",
+            Perk::IronStomach => "Iron Stomach"
+        };
+
+        write!(f, "{perk}")
+    }
+}
@@ -0,0 +1,159 @@
+use serde::{Serialize, Deserialize};
+use std::{fmt, time::SystemTime};
+
+use crate::{
+    clock,
+    entities::EntityId,
+    rng::GameRng,
+    util::{random_range, random_weighted_index}
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelationshipKind {
+    Stranger,
+    Friend,
+    Rival,
+    Family,
+    Partner
+}
+
+impl fmt::Display for RelationshipKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            RelationshipKind::Stranger => "Stranger",
+            RelationshipKind::Friend => "Friend",
+            RelationshipKind::Rival => "Rival",
+            RelationshipKind::Family => "Family",
+            RelationshipKind::Partner => "Partner"
+        };
+
+        write!(f, "{kind}")
+    }
+}
+
+/// A single moment that shaped a relationship
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Interaction {
+    pub time: SystemTime,
+    pub description: String,
+    pub affinity_change: f32,
+    pub trust_change: f32,
+}
+
+/// How one human feels about another, referenced by its id
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Relationship {
+    pub target: EntityId,
+    pub kind: RelationshipKind,
+    /// How much they like each other, from -100 (hatred) to 100 (love)
+    pub affinity: f32,
+    /// How much they rely on each other, from 0 to 100
+    pub trust: f32,
+    pub history: Vec<Interaction>,
+}
+
+impl Relationship {
+    pub const MIN_AFFINITY: f32 = -100.0;
+    pub const MAX_AFFINITY: f32 = 100.0;
+    pub const MAX_TRUST: f32 = 100.0;
+    /// Strangers become friends or rivals past these affinities
+    const FRIEND_AFFINITY: f32 = 40.0;
+    const RIVAL_AFFINITY: f32 = -40.0;
+    /// Only the most recent interactions are remembered
+    const MAX_HISTORY: usize = 20;
+
+    pub fn new(target: EntityId, kind: RelationshipKind) -> Self {
+        let (affinity, trust) = match kind {
+            RelationshipKind::Stranger => (0.0, 10.0),
+            RelationshipKind::Friend => (50.0, 50.0),
+            RelationshipKind::Rival => (-50.0, 5.0),
+            RelationshipKind::Family => (60.0, 70.0),
+            RelationshipKind::Partner => (80.0, 80.0)
+        };
+
+        Self {
+            target,
+            kind,
+            affinity,
+            trust,
+            history: Vec::new(),
+        }
+    }
+
+    /// Procedurally rolls how a human knew somebody before the outbreak
+    pub fn generate(rng: &mut GameRng, target: EntityId) -> Self {
+        const KINDS: [(RelationshipKind, f32); 5] = [
+            (RelationshipKind::Stranger, 60.0),
+            (RelationshipKind::Friend, 20.0),
+            (RelationshipKind::Rival, 10.0),
+            (RelationshipKind::Family, 7.0),
+            (RelationshipKind::Partner, 3.0)
+        ];
+
+        let weights = KINDS.map(|(_, weight)| weight);
+        let index = random_weighted_index(rng, &weights).unwrap_or(0);
+
+        let mut relationship = Self::new(target, KINDS[index].0);
+        relationship.affinity += random_range(rng, -10.0..10.0);
+        relationship.trust = (relationship.trust + random_range(rng, -5.0..5.0)).max(0.0);
+        relationship
+    }
+
+    /// Records an interaction and how it changed the relationship
+    pub fn record(&mut self, time: SystemTime, description: &str, affinity_change: f32, trust_change: f32) {
+        self.adjust(affinity_change, trust_change);
+
+        self.history.push(Interaction {
+            time,
+            description: description.to_string(),
+            affinity_change,
+            trust_change,
+        });
+
+        if self.history.len() > Self::MAX_HISTORY {
+            self.history.remove(0);
+        }
+    }
+
+    /// Nudges the relationship without remembering why. Used for small day to day changes
+    pub fn adjust(&mut self, affinity_change: f32, trust_change: f32) {
+        self.affinity = (self.affinity + affinity_change).clamp(Self::MIN_AFFINITY, Self::MAX_AFFINITY);
+        self.trust = (self.trust + trust_change).clamp(0.0, Self::MAX_TRUST);
+        self.update_kind();
+    }
+
+    /// Family and partners stay what they are, everybody else
+    /// drifts between stranger, friend and rival with affinity
+    fn update_kind(&mut self) {
+        if matches!(self.kind, RelationshipKind::Family | RelationshipKind::Partner) {
+            return;
+        }
+
+        self.kind = if self.affinity >= Self::FRIEND_AFFINITY {
+            RelationshipKind::Friend
+        } else if self.affinity <= Self::RIVAL_AFFINITY {
+            RelationshipKind::Rival
+        } else {
+            RelationshipKind::Stranger
+        };
+    }
+
+    pub fn get_formatted(&self) -> String {
+        let mut msg = format!(
+            "{} (Affinity: {:.0}, Trust: {:.0})",
+            self.kind,
+            self.affinity,
+            self.trust
+        );
+
+        for interaction in self.history.iter().rev().take(5) {
+            msg.push_str(&format!(
+                "\n  {} - {}",
+                clock::format_time(&interaction.time),
+                interaction.description
+            ));
+        }
+
+        msg
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
 pub enum EntityStat {
     Thirst(f32),
     Hunger(f32),
@@ -8,6 +8,7 @@ pub enum EntityStat {
     Health(f32)
 }
 
+/// Thirst and hunger build up from 0 to 100 while energy drains from 1 to 0
 #[derive(Serialize, Deserialize, Debug)]
 pub struct EntityStats {
     pub thirst: f32,
@@ -16,6 +17,24 @@ pub struct EntityStats {
     pub health: f32
 }
 
+/// How fast thirst, hunger and energy drain compared to normal
+#[derive(Debug, Clone, Copy)]
+pub struct StatDrain {
+    pub thirst: f32,
+    pub hunger: f32,
+    pub energy: f32,
+}
+
+impl Default for StatDrain {
+    fn default() -> Self {
+        Self {
+            thirst: 1.0,
+            hunger: 1.0,
+            energy: 1.0,
+        }
+    }
+}
+
 impl Default for EntityStats {
     fn default() -> Self {
         Self {
@@ -28,12 +47,131 @@ impl Default for EntityStats {
 }
 
 impl EntityStats {
+    pub const MAX_THIRST: f32 = 100.0;
+    pub const MAX_HUNGER: f32 = 100.0;
+    pub const MAX_ENERGY: f32 = 1.0;
+    pub const MAX_HEALTH: f32 = 100.0;
+
+    /* Decay per in-game hour */
+    pub const THIRST_PER_HOUR: f32 = 4.0;
+    pub const HUNGER_PER_HOUR: f32 = 2.0;
+    pub const ENERGY_PER_HOUR: f32 = 0.04;
+    /// Energy regained per hour of rest, on top of the regular decay
+    pub const REST_ENERGY_PER_HOUR: f32 = 0.15;
+
+    /* Past these thresholds the entity starts losing health */
+    pub const CRITICAL_THIRST: f32 = 80.0;
+    pub const CRITICAL_HUNGER: f32 = 80.0;
+    pub const CRITICAL_ENERGY: f32 = 0.1;
+
+    /// Health lost per hour for every stat in a critical state
+    pub const CRITICAL_DAMAGE_PER_HOUR: f32 = 5.0;
+    /// Health recovered per hour while nothing is critical
+    pub const RECOVERY_PER_HOUR: f32 = 0.5;
+
     pub fn get_formatted(&self) -> String {
         format!(
-            "Thirst: {}\nHunger: {}\nEnergy: {}",
+            "Health: {:.0}\nThirst: {:.0}\nHunger: {:.0}\nEnergy: {:.0}%",
+            self.health,
             self.thirst,
             self.hunger,
-            self.energy
+            self.energy * 100.0
         )
     }
-}
\ No newline at end of file
+
+    pub fn add_stat(&mut self, stat: EntityStat) {
+        match stat {
+            EntityStat::Thirst(value) => self.thirst = (self.thirst + value).clamp(0.0, Self::MAX_THIRST),
+            EntityStat::Hunger(value) => self.hunger = (self.hunger + value).clamp(0.0, Self::MAX_HUNGER),
+            EntityStat::Energy(value) => self.energy = (self.energy + value).clamp(0.0, Self::MAX_ENERGY),
+            EntityStat::Health(value) => self.health = (self.health + value).clamp(0.0, Self::MAX_HEALTH),
+        }
+    }
+
+    /// Thirsty enough to reach for a drink, well before it becomes critical
+    pub fn is_thirsty(&self) -> bool {
+        self.thirst >= Self::CRITICAL_THIRST / 2.0
+    }
+
+    /// Hungry enough to reach for a bite, well before it becomes critical
+    pub fn is_hungry(&self) -> bool {
+        self.hunger >= Self::CRITICAL_HUNGER / 2.0
+    }
+
+    pub fn is_thirst_critical(&self) -> bool {
+        self.thirst >= Self::CRITICAL_THIRST
+    }
+
+    pub fn is_hunger_critical(&self) -> bool {
+        self.hunger >= Self::CRITICAL_HUNGER
+    }
+
+    pub fn is_energy_critical(&self) -> bool {
+        self.energy <= Self::CRITICAL_ENERGY
+    }
+
+    /// Warnings for every stat currently in a critical state
+    pub fn get_warnings(&self) -> Vec<&'static str> {
+        let mut warnings = Vec::new();
+
+        if self.is_thirst_critical() {
+            warnings.push("Dehydrated");
+        }
+
+        if self.is_hunger_critical() {
+            warnings.push("Starving");
+        }
+
+        if self.is_energy_critical() {
+            warnings.push("Exhausted");
+        }
+
+        warnings
+    }
+
+    /// Applies the decay of the given amount of in-game hours, scaled by `drain`, and
+    /// the health consequences of any stat that became critical.
+    /// Health comes back at the given multiple of the regular recovery
+    pub fn decay(&mut self, hours: f32, drain: StatDrain, recovery: f32) {
+        self.add_stat(EntityStat::Thirst(Self::THIRST_PER_HOUR * drain.thirst * hours));
+        self.add_stat(EntityStat::Hunger(Self::HUNGER_PER_HOUR * drain.hunger * hours));
+        self.add_stat(EntityStat::Energy(-Self::ENERGY_PER_HOUR * drain.energy * hours));
+
+        let critical = self.get_warnings().len();
+        if critical > 0 {
+            let damage = Self::CRITICAL_DAMAGE_PER_HOUR * critical as f32 * hours;
+            self.add_stat(EntityStat::Health(-damage));
+        } else {
+            self.add_stat(EntityStat::Health(Self::RECOVERY_PER_HOUR * recovery * hours));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drain_scales_decay() {
+        let mut stats = EntityStats::default();
+        let drain = StatDrain { thirst: 0.5, hunger: 0.0, energy: 1.0 };
+
+        stats.decay(1.0, drain, 1.0);
+
+        assert_eq!(stats.thirst, EntityStats::THIRST_PER_HOUR * 0.5);
+        assert_eq!(stats.hunger, 0.0);
+        assert_eq!(stats.energy, EntityStats::MAX_ENERGY - EntityStats::ENERGY_PER_HOUR);
+    }
+
+    #[test]
+    fn reduced_drain_never_restores_stats() {
+        let mut stats = EntityStats { thirst: EntityStats::MAX_THIRST, energy: 0.0, ..Default::default() };
+        let drain = StatDrain { thirst: 0.75, hunger: 0.75, energy: 0.7 };
+
+        stats.decay(1.0, drain, 1.0);
+
+        assert_eq!(stats.thirst, EntityStats::MAX_THIRST);
+        assert_eq!(stats.energy, 0.0);
+        assert_eq!(stats.health, EntityStats::MAX_HEALTH - 2.0 * EntityStats::CRITICAL_DAMAGE_PER_HOUR);
+    }
+}
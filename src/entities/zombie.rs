@@ -0,0 +1,132 @@
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+use crate::{
+    rng::GameRng,
+    util::{random_range, random_weighted_index},
+    world::LocationId
+};
+use super::{Entity, EntityId, EntityType, Human};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZombieKind {
+    /// Slow and clumsy, the most common of the undead
+    Walker,
+    /// Freshly turned, fast and aggressive
+    Runner,
+    /// Bloated and tough, hits hard
+    Brute
+}
+
+impl fmt::Display for ZombieKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            ZombieKind::Walker => "Walker",
+            ZombieKind::Runner => "Runner",
+            ZombieKind::Brute => "Brute"
+        };
+
+        write!(f, "{kind}")
+    }
+}
+
+/// The stats every kind of undead shares
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct ZombieStats {
+    pub max_health: f32,
+    /// Damage dealt per successful hit
+    pub damage: f32,
+    /// Chance of landing a hit, from 0 to 1
+    pub accuracy: f32,
+    /// Chance of a successful hit also being a bite
+    pub bite_chance: f32,
+}
+
+impl ZombieKind {
+    pub fn get_stats(&self) -> ZombieStats {
+        match self {
+            ZombieKind::Walker => ZombieStats { max_health: 30.0, damage: 6.0, accuracy: 0.35, bite_chance: 0.15 },
+            ZombieKind::Runner => ZombieStats { max_health: 25.0, damage: 8.0, accuracy: 0.5, bite_chance: 0.2 },
+            ZombieKind::Brute => ZombieStats { max_health: 60.0, damage: 14.0, accuracy: 0.35, bite_chance: 0.1 },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Zombie {
+    pub id: EntityId,
+    pub kind: ZombieKind,
+    pub health: f32,
+    /// The name of the human this zombie used to be, if anybody knew them
+    pub former_name: Option<String>,
+    pub location: LocationId,
+    entity_type: EntityType,
+}
+
+impl Zombie {
+    /// Rolls a random undead at a location
+    pub fn new(rng: &mut GameRng, location: LocationId) -> Self {
+        const KINDS: [(ZombieKind, f32); 3] = [
+            (ZombieKind::Walker, 70.0),
+            (ZombieKind::Runner, 20.0),
+            (ZombieKind::Brute, 10.0)
+        ];
+
+        let weights = KINDS.map(|(_, weight)| weight);
+        let kind = KINDS[random_weighted_index(rng, &weights).unwrap_or(0)].0;
+        let max_health = kind.get_stats().max_health;
+
+        Self {
+            id: EntityId::new(rng),
+            kind,
+            health: random_range(rng, max_health * 0.7..=max_health),
+            former_name: None,
+            location,
+            entity_type: EntityType::Zombie,
+        }
+    }
+
+    /// A human that has turned. The zombie keeps their id so anything
+    /// that referenced them now refers to what they have become
+    pub fn from_human(human: &Human) -> Self {
+        let kind = ZombieKind::Runner;
+
+        Self {
+            id: human.id,
+            kind,
+            health: kind.get_stats().max_health,
+            former_name: Some(human.get_name_formatted()),
+            location: human.current_location,
+            entity_type: EntityType::Zombie,
+        }
+    }
+
+    pub fn get_stats(&self) -> ZombieStats {
+        self.kind.get_stats()
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.health > 0.0
+    }
+
+    pub fn get_name_formatted(&self) -> String {
+        match &self.former_name {
+            Some(name) => format!("{} (what used to be {})", self.kind, name),
+            None => self.kind.to_string()
+        }
+    }
+}
+
+impl Entity for Zombie {
+    fn get_id(&self) -> EntityId {
+        self.id
+    }
+
+    fn get_entity_type(&self) -> EntityType {
+        self.entity_type
+    }
+
+    fn get_entity_save_data(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
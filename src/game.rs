@@ -1,117 +1,388 @@
 use serde::{Serialize, Deserialize};
-use bincode;
 use std::{
+    env,
     fs,
     io,
     sync::{Arc, Mutex},
     time::{Duration, UNIX_EPOCH, SystemTime}
 };
 
-use crate::entities::Player;
+use crate::clock::{self, DayPhase};
+use crate::entities::{EntityId, EntityRegistry, Player, RegisteredEntity, Zombie};
+use crate::rng::GameRng;
+use crate::safehouse::Safehouse;
+use crate::saves::{SaveSlot, SlotMetadata, SAVE_FOLDER};
+use crate::world::World;
 use crate::util::{
+    collect_input_str,
+    collect_with_options,
     folder_exists,
     load_from_file_bin,
-    save_to_file_bin
+    save_to_file_bin,
+    sleep
 };
 
 // Tue Jan 01 1985 05:00:00 GMT+0000
-const GAME_START_EPOCH: u64 = 1672549200;
+pub(crate) const GAME_START_EPOCH: u64 = 473403600;
 const GAME_START_DURATION: Duration = Duration::from_secs(GAME_START_EPOCH);
 
-#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct Game {
     pub time: SystemTime,
+    /// Every random roll in the game is drawn from this generator
+    pub rng: GameRng,
+    /// Every entity in the world besides the player
+    pub entities: EntityRegistry,
+    pub world: World,
+    /// The location the player claimed as their own, if any
+    pub safehouse: Option<Safehouse>,
+    /// Where the game is saved to
+    #[serde(skip)]
+    pub slot: SaveSlot,
 }
 
-// pub enum GameTickSignal {
-//     Continue,
-//     Exit
-// }
+/// What the game loop should do after a tick
+pub enum GameTickSignal {
+    /// Keep playing after the given amount of in-game time has passed
+    Continue(Duration),
+    /// The player has died
+    GameOver,
+    Exit
+}
 
 impl Default for Game {
     fn default() -> Self {
-        Self { time: UNIX_EPOCH + GAME_START_DURATION }
+        Self::from_seed(GameRng::entropy_seed())
     }
 }
 
 impl Game {
-    const GAME_FILEPATH: &'static str = "saved/game.dat";
-    const _COUNTRY: &'static str = "USA";
-    const _CITY: &'static str = "New York";
+    /// Environment variable used to start a new game from a known seed
+    const SEED_VAR: &'static str = "CODE_UNDEAD_SEED";
+    pub const COUNTRY: &'static str = "USA";
+    pub const CITY: &'static str = "New York";
 
     pub fn init() {
-        const SAVE_FOLDER: &str = "saved";
-        let game;
-
         // If the save folder doesnt exist, create it
         if !folder_exists(SAVE_FOLDER) {
-            if !fs::metadata(SAVE_FOLDER).is_ok() {
-                fs::create_dir(SAVE_FOLDER)
-                    .expect("Failed to create save folder");
-            
-                game = Self::new();
-            } else {
-                game = Self::load().ok().unwrap();
-            }
-        } else {
-            game = Self::load().ok().unwrap();
+            fs::create_dir(SAVE_FOLDER)
+                .expect("Failed to create save folder");
         }
 
+        let Some(game) = Self::print_title_menu() else {
+            return;
+        };
+
         let game_arc = Arc::new(Mutex::new(game));
-        let player = Player::init(Arc::clone(&game_arc));
+        let mut player = Player::init(Arc::clone(&game_arc));
         
         let game = Arc::clone(&game_arc);
 
         loop {
-            let game = game.lock().unwrap();
-            let shutdown_signal = game.tick(&player);
-            if shutdown_signal {
-                Self::exit_gracefully();
-                break;
+            let mut game = game.lock().unwrap();
+            match game.tick(&mut player) {
+                GameTickSignal::Continue(_) => {},
+                GameTickSignal::GameOver => {
+                    game.game_over(&player);
+                    break;
+                },
+                GameTickSignal::Exit => {
+                    game.exit_gracefully(&player);
+                    break;
+                }
             }
         }
     }
 
-    fn tick(&self, player: &Player) -> bool {
-        player.print_menu()
+    /// Lets the player start a new game or pick up a saved one.
+    /// Returns nothing if they would rather quit
+    fn print_title_menu() -> Option<Self> {
+        loop {
+            let slots = SaveSlot::list();
+
+            let prompt = collect_with_options(
+                "CODE UNDEAD",
+                &["New game", "Continue", "Load game", "Delete game", "Exit"]
+            );
+
+            let prompt = prompt.unwrap();
+            if (1..=3).contains(&prompt) && slots.is_empty() {
+                println!("There are no saved games\n");
+                sleep(1000);
+                continue;
+            }
+
+            let slot = match prompt {
+                0 => match Self::name_slot() {
+                    Some(slot) => return Some(Self::new(slot)),
+                    None => continue
+                },
+                1 => slots[0].0.clone(),
+                2 => match Self::choose_slot(&slots, "Which game would you like to load?") {
+                    Some(slot) => slot,
+                    None => continue
+                },
+                3 => {
+                    if let Some(slot) = Self::choose_slot(&slots, "Which game would you like to delete?") {
+                        Self::delete_slot(&slot);
+                    }
+                    continue;
+                },
+                _ => return None
+            };
+
+            match Self::load(slot) {
+                Ok(game) => return Some(game),
+                Err(err) => {
+                    println!("{err}\n");
+                    sleep(1000);
+                }
+            }
+        }
     }
 
-    fn exit_gracefully() {
+    /// Asks for the name of a new save slot. Returns nothing if it can not be used
+    fn name_slot() -> Option<SaveSlot> {
+        let name = collect_input_str(Some("What would you like to call this game?")).ok()?;
+
+        let Some(slot) = SaveSlot::new(&name) else {
+            println!("Names can only have letters, numbers, spaces, dashes and underscores\n");
+            sleep(1000);
+            return None;
+        };
+
+        if slot.exists() {
+            println!("There already is a game called {}\n", slot.name);
+            sleep(1000);
+            return None;
+        }
 
+        Some(slot)
     }
 
-    // A new game is started, as opposed to being loaded from file
-    fn new() -> Self {
-        let game = Self::default();
-        let _ = game.save();
+    fn choose_slot(slots: &[(SaveSlot, Option<SlotMetadata>)], text: &str) -> Option<SaveSlot> {
+        let options = slots
+            .iter()
+            .map(|(slot, metadata)| match metadata {
+                Some(metadata) => format!("{} - {}", slot.name, metadata.get_formatted()),
+                None => slot.name.clone()
+            })
+            .collect::<Vec<_>>();
+
+        let mut options = options.iter().map(String::as_str).collect::<Vec<_>>();
+        options.push("Back");
+
+        let index = collect_with_options(text, &options).unwrap();
+        slots.get(index).map(|(slot, _)| slot.clone())
+    }
+
+    fn delete_slot(slot: &SaveSlot) {
+        let prompt = collect_with_options(
+            &format!("Delete {}? This can not be undone", slot.name),
+            &["Yes", "No"]
+        );
+
+        if prompt.unwrap() != 0 {
+            return;
+        }
+
+        match slot.delete() {
+            Ok(()) => println!("{} was deleted\n", slot.name),
+            Err(_) => println!("{} could not be deleted\n", slot.name)
+        }
+        sleep(1000);
+    }
+
+    fn tick(&mut self, player: &mut Player) -> GameTickSignal {
+        let signal = player.print_menu(self);
+
+        if let GameTickSignal::Continue(elapsed) = signal {
+            self.advance_time(elapsed);
+            player.human.update(elapsed);
+            for kind in player.human.roll_conditions(&mut self.rng, elapsed) {
+                println!("You have come down with something ({kind})\n");
+            }
+
+            let turned = self.update_entities(elapsed);
+            for id in turned.iter().copied() {
+                if let (Some(relationship), Some(RegisteredEntity::Zombie(zombie))) =
+                    (player.human.get_relationship(id), self.entities.get(id))
+                {
+                    println!(
+                        "{} ({}) has turned into one of the undead...\n",
+                        zombie.former_name.as_deref().unwrap_or("Someone you knew"),
+                        relationship.kind
+                    );
+                }
+            }
+
+            self.update_hordes(player, elapsed);
+            player.update_party(self, elapsed, &turned);
+            player.update_safehouse(self, elapsed);
+
+            if !player.human.is_alive() {
+                return GameTickSignal::GameOver;
+            }
+        }
+
+        signal
+    }
+
+    /// Lets time pass for every other entity in the world.
+    /// Returns the ids of the humans that turned into undead
+    fn update_entities(&mut self, elapsed: Duration) -> Vec<EntityId> {
+        let mut turned = Vec::new();
+        let mut dead = Vec::new();
+
+        for human in self.entities.humans_mut() {
+            human.update(elapsed);
+            human.roll_conditions(&mut self.rng, elapsed);
+            human.tend_to_needs();
 
+            // The infected come back, everybody else stays dead
+            if human.has_turned() || (!human.is_alive() && human.is_infected()) {
+                turned.push(human.id);
+            } else if !human.is_alive() {
+                dead.push(human.id);
+            }
+        }
+
+        for id in &turned {
+            if let Some(RegisteredEntity::Human(human)) = self.entities.remove(*id) {
+                self.entities.insert(RegisteredEntity::Zombie(Zombie::from_human(&human)));
+            }
+        }
+
+        for id in dead {
+            self.entities.remove(id);
+        }
+
+        turned
+    }
+
+    /// Moves hordes around the city, letting the player know about any that come or go
+    fn update_hordes(&mut self, player: &Player, elapsed: Duration) {
+        if let Some(safehouse) = &self.safehouse {
+            let noise = safehouse.get_noise_per_hour() * elapsed.as_secs_f32() / 3600.0;
+            self.world.make_noise(safehouse.location, noise);
+        }
+
+        let day_phase = self.get_day_phase();
+        let location = player.human.current_location;
+
+        for movement in self.world.update_hordes(&mut self.rng, elapsed, day_phase) {
+            if movement.horde.location == location {
+                println!("{} shambles into the area...\n", movement.horde.get_size_formatted());
+            } else if movement.from == location {
+                println!("The undead around here move on\n");
+            }
+        }
+    }
+
+    /// The player has died. Their save is wiped so the next launch starts over
+    fn game_over(&self, player: &Player) {
+        if player.human.has_turned() {
+            println!(
+                "The fever finally takes {}.\n\
+                Moments later, something that used to be them gets back up...\n",
+                player.human.get_name_formatted()
+            );
+        }
+
+        println!(
+            "{} has died on {}.\n\
+            They survived {} days after the outbreak.\n\n\
+            GAME OVER",
+            player.human.get_name_formatted(),
+            self.get_time_formatted(),
+            self.get_days_survived()
+        );
+
+        let _ = self.slot.delete();
+    }
+
+    fn exit_gracefully(&self, player: &Player) {
+        let _ = player.save(&self.slot);
+        let _ = self.save();
+        let _ = self.slot.save_metadata(&SlotMetadata::new(self, player));
+    }
+
+    /// Moves the in-game clock forward
+    pub fn advance_time(&mut self, elapsed: Duration) {
+        self.time += elapsed;
+    }
+
+    pub fn get_time_formatted(&self) -> String {
+        clock::format_time(&self.time)
+    }
+
+    pub fn get_day_phase(&self) -> DayPhase {
+        DayPhase::from_hour(clock::hour_of_day(&self.time))
+    }
+
+    /// How many whole days have passed since the outbreak
+    pub fn get_days_survived(&self) -> u64 {
+        self.time
+            .duration_since(UNIX_EPOCH + GAME_START_DURATION)
+            .unwrap_or_default()
+            .as_secs() / clock::hours(24).as_secs()
+    }
+
+    /// A fresh world, generated entirely from the given seed
+    fn from_seed(seed: u64) -> Self {
+        Self {
+            time: UNIX_EPOCH + GAME_START_DURATION,
+            rng: GameRng::new(seed),
+            entities: EntityRegistry::default(),
+            world: World::generate(seed),
+            safehouse: None,
+            slot: SaveSlot::default()
+        }
+    }
+
+    // A new game is started in the given slot, as opposed to being loaded from file
+    fn new(slot: SaveSlot) -> Self {
+        let seed = env::var(Self::SEED_VAR)
+            .ok()
+            .and_then(|seed| seed.parse::<u64>().ok())
+            .unwrap_or_else(GameRng::entropy_seed);
+
+        slot.create().expect("Failed to create save slot");
+
+        let mut game = Self::from_seed(seed);
+        game.slot = slot;
         let game_arc = Arc::new(Mutex::new(game));
 
         let player = Player::init(Arc::clone(&game_arc));
-        let _ = player.save();
+        let metadata = SlotMetadata::new(&game_arc.lock().unwrap(), &player);
+        drop(player);
 
-        let game = Arc::clone(&game_arc);
-        let game = *game.lock().unwrap();
+        // Save after the player was rolled so the generator state matches
+        let game = Arc::try_unwrap(game_arc)
+            .expect("The game is still shared")
+            .into_inner()
+            .unwrap();
+        let _ = game.save();
+        let _ = game.slot.save_metadata(&metadata);
 
-        return game;
+        game
     }
 
-    // A new game is loaded from file
-    fn load() -> Result<Self, &'static str>  {
-        let file = load_from_file_bin(Self::GAME_FILEPATH)
+    // A game is loaded from the given slot
+    fn load(slot: SaveSlot) -> Result<Self, &'static str>  {
+        let file = load_from_file_bin(&slot.get_game_path())
             .expect("Failed to load game file");
 
-        // There is no data stored yet
-        // Create a new one and return here
-        if file.len() <= 0 {
-            let game = Self::default();
-            let _ = game.save();
-            return Ok(game);
+        // There is no data stored in this slot
+        if file.is_empty() {
+            return Err("There is no game saved there");
         }
 
-        let game: Self = bincode::deserialize(&file)
-            .expect("Failed to load game from file");
+        // Bincode does not describe its own layout, so saves from older versions can not be read
+        let mut game: Self = bincode::deserialize(&file)
+            .map_err(|_| "This save is from an incompatible version")?;
 
+        game.slot = slot;
         Ok(game)
     }
 
@@ -119,6 +390,6 @@ impl Game {
         let serialized = bincode::serialize(self)
             .expect("Failed to serialize game");
 
-        save_to_file_bin(Self::GAME_FILEPATH, &serialized)
+        save_to_file_bin(&self.slot.get_game_path(), &serialized)
     }
 }
\ No newline at end of file
@@ -0,0 +1,225 @@
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+use super::Item;
+
+#[derive(Debug, Clone, Copy)]
+pub enum InventorySort {
+    Name,
+    Category,
+    Weight
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Inventory {
+    items: Vec<Item>,
+}
+
+impl Inventory {
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Item> {
+        self.items.get(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Item> {
+        self.items.get_mut(index)
+    }
+
+    /// The index of the first stack of an item id
+    pub fn position_of(&self, id: &str) -> Option<usize> {
+        self.items.iter().position(|item| item.id == id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn get_total_weight(&self) -> f32 {
+        self.items.iter().map(Item::get_total_weight).sum()
+    }
+
+    /// How many units of an item id are held across every stack
+    pub fn count_of(&self, id: &str) -> u32 {
+        self.items
+            .iter()
+            .filter(|item| item.id == id)
+            .map(|item| item.count)
+            .sum()
+    }
+
+    /// Adds an item, filling up existing stacks before creating new ones
+    pub fn add(&mut self, mut item: Item) {
+        for stack in self.items.iter_mut() {
+            if item.count == 0 {
+                return;
+            }
+
+            if stack.can_stack_with(&item) {
+                let moved = (stack.max_stack - stack.count).min(item.count);
+                stack.count += moved;
+                item.count -= moved;
+            }
+        }
+
+        while item.count > 0 {
+            let count = item.count.min(item.max_stack.max(1));
+            item.count -= count;
+            self.items.push(Item { count, ..item.clone() });
+        }
+    }
+
+    /// Takes up to `count` units out of the stack at the given index
+    pub fn take(&mut self, index: usize, count: u32) -> Option<Item> {
+        let stack = self.items.get_mut(index)?;
+        let count = count.min(stack.count);
+
+        if count == stack.count {
+            return Some(self.items.remove(index));
+        }
+
+        stack.count -= count;
+        Some(Item { count, ..stack.clone() })
+    }
+
+    /// Removes up to `count` units of an item id across every stack.
+    /// Returns how many units were removed
+    pub fn remove_by_id(&mut self, id: &str, count: u32) -> u32 {
+        let mut removed = 0;
+
+        while removed < count {
+            let Some(index) = self.items.iter().position(|item| item.id == id) else {
+                break;
+            };
+
+            if let Some(item) = self.take(index, count - removed) {
+                removed += item.count;
+            }
+        }
+
+        removed
+    }
+
+    pub fn sort(&mut self, sort: InventorySort) {
+        match sort {
+            InventorySort::Name => self.items.sort_by(|a, b| a.name.cmp(&b.name)),
+            InventorySort::Category => self.items.sort_by(|a, b| {
+                a.category.cmp(&b.category).then_with(|| a.name.cmp(&b.name))
+            }),
+            InventorySort::Weight => self.items.sort_by(|a, b| {
+                b.get_total_weight().total_cmp(&a.get_total_weight())
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Inventory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.items.is_empty() {
+            return write!(f, "Your inventory is empty");
+        }
+
+        for item in &self.items {
+            writeln!(f, "- {item}")?;
+        }
+
+        write!(f, "Total weight: {:.2}kg", self.get_total_weight())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn water(count: u32) -> Item {
+        Item::new("water_bottle", count).expect("water_bottle should be defined")
+    }
+
+    #[test]
+    fn add_fills_existing_stacks_first() {
+        let mut inventory = Inventory::default();
+        let max_stack = water(1).max_stack;
+
+        inventory.add(water(max_stack - 2));
+        inventory.add(water(5));
+
+        assert_eq!(inventory.len(), 2);
+        assert_eq!(inventory.get(0).unwrap().count, max_stack);
+        assert_eq!(inventory.get(1).unwrap().count, 3);
+    }
+
+    #[test]
+    fn add_splits_oversized_stacks() {
+        let mut inventory = Inventory::default();
+        let max_stack = water(1).max_stack;
+
+        inventory.add(water(max_stack * 2 + 1));
+
+        assert_eq!(inventory.len(), 3);
+        assert_eq!(inventory.count_of("water_bottle"), max_stack * 2 + 1);
+    }
+
+    #[test]
+    fn take_part_of_a_stack() {
+        let mut inventory = Inventory::default();
+        inventory.add(water(5));
+
+        let taken = inventory.take(0, 2).unwrap();
+
+        assert_eq!(taken.count, 2);
+        assert_eq!(inventory.get(0).unwrap().count, 3);
+    }
+
+    #[test]
+    fn take_whole_stack_removes_it() {
+        let mut inventory = Inventory::default();
+        inventory.add(water(5));
+
+        let taken = inventory.take(0, 10).unwrap();
+
+        assert_eq!(taken.count, 5);
+        assert!(inventory.is_empty());
+        assert!(inventory.take(0, 1).is_none());
+    }
+
+    #[test]
+    fn remove_by_id_across_stacks() {
+        let mut inventory = Inventory::default();
+        let max_stack = water(1).max_stack;
+        inventory.add(water(max_stack + 3));
+
+        let removed = inventory.remove_by_id("water_bottle", max_stack + 1);
+
+        assert_eq!(removed, max_stack + 1);
+        assert_eq!(inventory.count_of("water_bottle"), 2);
+        assert_eq!(inventory.len(), 1);
+    }
+
+    #[test]
+    fn remove_by_id_stops_when_out() {
+        let mut inventory = Inventory::default();
+        inventory.add(water(3));
+
+        assert_eq!(inventory.remove_by_id("water_bottle", 5), 3);
+        assert_eq!(inventory.remove_by_id("bandage", 1), 0);
+        assert!(inventory.is_empty());
+    }
+
+    #[test]
+    fn count_of_sums_matching_stacks() {
+        let mut inventory = Inventory::default();
+        inventory.add(water(4));
+        inventory.add(Item::new("bandage", 2).unwrap());
+        inventory.add(water(3));
+
+        assert_eq!(inventory.count_of("water_bottle"), 7);
+        assert_eq!(inventory.count_of("bandage"), 2);
+        assert_eq!(inventory.count_of("crowbar"), 0);
+    }
+}
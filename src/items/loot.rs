@@ -0,0 +1,117 @@
+use serde::{Serialize, Deserialize};
+use std::sync::OnceLock;
+
+use crate::{
+    entities::EntityAttributes,
+    rng::GameRng,
+    util::{load_from_file, random, random_range, random_weighted_index}
+};
+
+use super::Item;
+
+const LOOT_TABLES_FILEPATH: &str = "./data/loot_tables.json";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LootEntry {
+    /// Id of the item definition this entry drops
+    pub item: String,
+    pub weight: f32,
+    #[serde(default = "LootEntry::default_amount")]
+    pub min: u32,
+    #[serde(default = "LootEntry::default_amount")]
+    pub max: u32,
+}
+
+impl LootEntry {
+    fn default_amount() -> u32 {
+        1
+    }
+}
+
+/// A weighted list of what can be found while scavenging a kind of location.
+/// Tables are loaded from `data/loot_tables.json`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LootTable {
+    pub id: String,
+    /// How many times the table is rolled per scavenge
+    pub rolls: u32,
+    /// Chance of running into the undead while scavenging here
+    pub zombie_chance: f32,
+    pub entries: Vec<LootEntry>,
+}
+
+impl LootTable {
+    /// Entries at or below this weight count as rare
+    const RARE_WEIGHT: f32 = 5.0;
+    /// Chance of a roll turning up nothing at all with no luck
+    const BASE_MISS_CHANCE: f32 = 0.35;
+    const MISS_CHANCE_PER_LUCK: f32 = 0.03;
+    /// How much more likely rare entries become per point of luck
+    const RARE_BONUS_PER_LUCK: f32 = 0.15;
+    /// Points of intelligence needed for an extra roll
+    const INTELLIGENCE_PER_ROLL: u8 = 3;
+
+    pub fn all() -> &'static [LootTable] {
+        static TABLES: OnceLock<Vec<LootTable>> = OnceLock::new();
+
+        TABLES.get_or_init(|| {
+            let file = load_from_file(LOOT_TABLES_FILEPATH)
+                .expect("Failed to load loot tables file");
+
+            serde_json::from_str(&file)
+                .expect("Failed to parse loot tables file")
+        })
+    }
+
+    /// Finds a table by id, falling back to the street table
+    pub fn get(id: &str) -> &'static LootTable {
+        let tables = Self::all();
+
+        tables
+            .iter()
+            .find(|table| table.id == id)
+            .or_else(|| tables.iter().find(|table| table.id == "street"))
+            .expect("The street loot table is missing")
+    }
+
+    /// Rolls the table. Intelligence grants extra rolls while luck
+    /// lowers the chance of finding nothing and favours rare entries.
+    /// The less richness left, the more rolls come up empty
+    pub fn roll(&self, rng: &mut GameRng, attributes: &EntityAttributes, richness: f32) -> Vec<Item> {
+        let luck = attributes.luck as f32;
+        let rolls = self.rolls + (attributes.intelligence / Self::INTELLIGENCE_PER_ROLL) as u32;
+        let miss_chance = (Self::BASE_MISS_CHANCE - Self::MISS_CHANCE_PER_LUCK * luck).max(0.05);
+        let miss_chance = miss_chance + (1.0 - miss_chance) * (1.0 - richness.clamp(0.0, 1.0)) * 0.8;
+
+        let weights = self.entries
+            .iter()
+            .map(|entry| {
+                if entry.weight <= Self::RARE_WEIGHT {
+                    entry.weight * (1.0 + Self::RARE_BONUS_PER_LUCK * luck)
+                } else {
+                    entry.weight
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut found = Vec::new();
+        for _ in 0..rolls {
+            if random(rng) < miss_chance {
+                continue;
+            }
+
+            let Some(index) = random_weighted_index(rng, &weights) else {
+                continue;
+            };
+
+            let entry = &self.entries[index];
+            let count = random_range(rng, entry.min as f32..(entry.max + 1) as f32) as u32;
+
+            if let Some(item) = Item::new(&entry.item, count) {
+                found.push(item);
+            }
+        }
+
+        found
+    }
+}
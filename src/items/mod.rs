@@ -0,0 +1,233 @@
+use serde::{Serialize, Deserialize};
+use std::{fmt, sync::OnceLock};
+
+use crate::{
+    entities::{Condition, ConditionKind, EntityStat, EntityStats},
+    util::load_from_file
+};
+
+mod inventory;
+mod loot;
+
+pub use inventory::{Inventory, InventorySort};
+pub use loot::{LootEntry, LootTable};
+
+const ITEMS_FILEPATH: &str = "./data/items.json";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ItemCategory {
+    Food,
+    Water,
+    Medicine,
+    Weapon,
+    Tool,
+    Material
+}
+
+impl ItemCategory {
+    /// Whether using an item of this category consumes it
+    pub fn is_consumable(&self) -> bool {
+        matches!(self, ItemCategory::Food | ItemCategory::Water | ItemCategory::Medicine)
+    }
+}
+
+impl fmt::Display for ItemCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let category = match self {
+            ItemCategory::Food => "Food",
+            ItemCategory::Water => "Water",
+            ItemCategory::Medicine => "Medicine",
+            ItemCategory::Weapon => "Weapon",
+            ItemCategory::Tool => "Tool",
+            ItemCategory::Material => "Material"
+        };
+
+        write!(f, "{category}")
+    }
+}
+
+/// A stack of items. Definitions are loaded from `data/items.json`
+/// and every stack in an inventory is a copy of its definition
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Item {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub category: ItemCategory,
+    /// Weight of a single unit in kilograms
+    pub weight: f32,
+    /// What a single unit is worth when bartering with other survivors
+    #[serde(default)]
+    pub value: f32,
+    /// Remaining uses of tools and weapons. Items without durability never break
+    #[serde(default)]
+    pub durability: Option<f32>,
+    pub max_stack: u32,
+    #[serde(default = "Item::default_count")]
+    pub count: u32,
+    /// Changes applied to the stats of whoever uses this item
+    #[serde(default)]
+    pub effects: Vec<EntityStat>,
+    /// Damage dealt when fighting with this item
+    #[serde(default)]
+    pub damage: Option<f32>,
+    /// Whether fighting with this item makes a lot of noise
+    #[serde(default)]
+    pub loud: bool,
+    /// Diseases and injuries this item helps heal when used
+    #[serde(default)]
+    pub treats: Vec<ConditionKind>,
+    /// Diseases caught from using this item
+    #[serde(default)]
+    pub causes: Vec<Condition>,
+}
+
+impl Item {
+    fn default_count() -> u32 {
+        1
+    }
+
+    /// Every item definition from the data file
+    pub fn definitions() -> &'static [Item] {
+        static DEFINITIONS: OnceLock<Vec<Item>> = OnceLock::new();
+
+        DEFINITIONS.get_or_init(|| {
+            let file = load_from_file(ITEMS_FILEPATH)
+                .expect("Failed to load items file");
+
+            serde_json::from_str(&file)
+                .expect("Failed to parse items file")
+        })
+    }
+
+    /// Creates a stack of the item with the given id. There is no such thing as an empty stack
+    pub fn new(id: &str, count: u32) -> Option<Self> {
+        if count == 0 {
+            return None;
+        }
+
+        Self::definitions()
+            .iter()
+            .find(|item| item.id == id)
+            .map(|item| Self { count, ..item.clone() })
+    }
+
+    pub fn get_total_weight(&self) -> f32 {
+        self.weight * self.count as f32
+    }
+
+    /// What the whole stack is worth when bartering. Worn out tools
+    /// and weapons are worth less than fresh ones
+    pub fn get_total_value(&self) -> f32 {
+        let condition = match (self.durability, Self::new(&self.id, 1).and_then(|item| item.durability)) {
+            (Some(durability), Some(max)) if max > 0.0 => (durability / max).clamp(0.0, 1.0),
+            _ => 1.0
+        };
+
+        self.value * self.count as f32 * condition
+    }
+
+    /// Whether another stack can be merged into this one
+    pub fn can_stack_with(&self, other: &Item) -> bool {
+        self.id == other.id && self.durability.is_none() && self.count < self.max_stack
+    }
+
+    /// Whether this item can be wielded in combat
+    pub fn is_weapon(&self) -> bool {
+        self.damage.is_some()
+    }
+
+    /// Wears the item down. Returns whether it broke
+    pub fn wear(&mut self, amount: f32) -> bool {
+        match self.durability.as_mut() {
+            Some(durability) => {
+                *durability = (*durability - amount).max(0.0);
+                *durability <= 0.0
+            },
+            None => false
+        }
+    }
+
+    /// Applies the effects of this item onto a set of stats
+    pub fn apply_effects(&self, stats: &mut EntityStats) {
+        for effect in &self.effects {
+            stats.add_stat(*effect);
+        }
+    }
+
+    pub fn get_effects_formatted(&self) -> String {
+        if self.effects.is_empty() {
+            return String::from("None");
+        }
+
+        self.effects
+            .iter()
+            .map(|effect| match effect {
+                EntityStat::Thirst(value) => format!("Thirst {:+.0}", value),
+                EntityStat::Hunger(value) => format!("Hunger {:+.0}", value),
+                EntityStat::Energy(value) => format!("Energy {:+.0}%", value * 100.0),
+                EntityStat::Health(value) => format!("Health {:+.0}", value),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    pub fn get_formatted(&self) -> String {
+        let mut msg = format!(
+            "{}\n{}\n\nCategory: {}\nWeight: {:.2}kg (x{} = {:.2}kg)\nEffects: {}",
+            self.name,
+            self.description,
+            self.category,
+            self.weight,
+            self.count,
+            self.get_total_weight(),
+            self.get_effects_formatted()
+        );
+
+        if !self.treats.is_empty() {
+            let treats = self.treats.iter().map(ConditionKind::to_string).collect::<Vec<_>>();
+            msg.push_str(&format!("\nTreats: {}", treats.join(", ")));
+        }
+
+        if let Some(damage) = self.damage {
+            msg.push_str(&format!("\nDamage: {:.0}", damage));
+        }
+
+        if let Some(durability) = self.durability {
+            msg.push_str(&format!("\nDurability: {:.0}", durability));
+        }
+
+        msg.push_str(&format!("\nValue: {:.0}", self.get_total_value()));
+
+        msg
+    }
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.count > 1 {
+            write!(f, "{} x{} ({:.2}kg)", self.name, self.count, self.get_total_weight())
+        } else {
+            write!(f, "{} ({:.2}kg)", self.name, self.get_total_weight())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_creates_a_stack_of_the_given_size() {
+        let item = Item::new("water_bottle", 3).unwrap();
+
+        assert_eq!(item.id, "water_bottle");
+        assert_eq!(item.count, 3);
+    }
+
+    #[test]
+    fn empty_stacks_cannot_be_created() {
+        assert!(Item::new("water_bottle", 0).is_none());
+        assert!(Item::new("no_such_item", 1).is_none());
+    }
+}
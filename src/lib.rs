@@ -1,5 +1,17 @@
 pub mod util;
+pub mod rng;
+pub mod clock;
+pub mod combat;
+pub mod dialogue;
 pub mod entities;
+pub mod items;
+pub mod party;
+pub mod safehouse;
+pub mod saves;
+pub mod scavenge;
+pub mod skill_checks;
+pub mod survivors;
+pub mod world;
 mod game;
 
-pub use game::Game;
\ No newline at end of file
+pub use game::{Game, GameTickSignal};
\ No newline at end of file
@@ -0,0 +1,160 @@
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+use crate::entities::{EntityId, EntityRegistry, Human, RegisteredEntity};
+
+/// A job a companion takes on for the party
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompanionRole {
+    /// Keeps an eye out, making ambushes less likely
+    Scout,
+    /// Keeps watch while the others rest
+    Guard,
+    /// Patches up the player over time
+    Medic,
+    /// Searches alongside the player, finding more loot
+    Scavenger
+}
+
+impl CompanionRole {
+    pub const ALL: [CompanionRole; 4] = [
+        CompanionRole::Scout,
+        CompanionRole::Guard,
+        CompanionRole::Medic,
+        CompanionRole::Scavenger
+    ];
+
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            CompanionRole::Scout => "Makes ambushes less likely",
+            CompanionRole::Guard => "Keeps watch so resting restores more energy",
+            CompanionRole::Medic => "Slowly heals the player",
+            CompanionRole::Scavenger => "Finds extra loot while scavenging"
+        }
+    }
+}
+
+impl fmt::Display for CompanionRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let role = match self {
+            CompanionRole::Scout => "Scout",
+            CompanionRole::Guard => "Guard",
+            CompanionRole::Medic => "Medic",
+            CompanionRole::Scavenger => "Scavenger"
+        };
+
+        write!(f, "{role}")
+    }
+}
+
+/// A survivor travelling with the player. The human itself lives in the entity registry
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PartyMember {
+    pub id: EntityId,
+    /// Kept around so they can be remembered once they are gone
+    pub name: String,
+    pub role: Option<CompanionRole>,
+}
+
+/// The survivors who agreed to stick with the player
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Party {
+    members: Vec<PartyMember>,
+}
+
+impl Party {
+    /// Ambush chances are multiplied by this with a scout around
+    pub const SCOUT_AMBUSH_MULTIPLIER: f32 = 0.6;
+    /// Resting restores this multiple of the energy with a guard on watch
+    pub const GUARD_REST_MULTIPLIER: f32 = 1.5;
+    /// Health a medic restores to the player per hour
+    pub const MEDIC_HEALING_PER_HOUR: f32 = 1.0;
+    /// Below this loyalty a companion may walk away
+    pub const LEAVE_LOYALTY: f32 = 0.0;
+    /// Below this loyalty an angry companion may rob the party on the way out
+    pub const BETRAY_LOYALTY: f32 = -30.0;
+    /// Chance per hour of a disloyal companion acting on it
+    pub const DESERTION_CHANCE_PER_HOUR: f32 = 0.1;
+    const LOYALTY_PER_TRUST: f32 = 0.5;
+    const LOYALTY_PER_MOOD: f32 = 30.0;
+
+    pub fn members(&self) -> &[PartyMember] {
+        &self.members
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    pub fn contains(&self, id: EntityId) -> bool {
+        self.members.iter().any(|member| member.id == id)
+    }
+
+    pub fn add(&mut self, human: &Human) {
+        if self.contains(human.id) {
+            return;
+        }
+
+        self.members.push(PartyMember {
+            id: human.id,
+            name: human.get_name_formatted(),
+            role: None,
+        });
+    }
+
+    pub fn remove(&mut self, id: EntityId) -> Option<PartyMember> {
+        let index = self.members.iter().position(|member| member.id == id)?;
+        Some(self.members.remove(index))
+    }
+
+    pub fn get_mut(&mut self, id: EntityId) -> Option<&mut PartyMember> {
+        self.members.iter_mut().find(|member| member.id == id)
+    }
+
+    pub fn has_role(&self, role: CompanionRole) -> bool {
+        self.members.iter().any(|member| member.role == Some(role))
+    }
+
+    /// The ids of every member with the given role
+    pub fn with_role(&self, role: CompanionRole) -> impl Iterator<Item = EntityId> + '_ {
+        self.members
+            .iter()
+            .filter(move |member| member.role == Some(role))
+            .map(|member| member.id)
+    }
+
+    /// How committed a companion is to the party, from how they feel about the player and their mood
+    pub fn get_loyalty(companion: &Human, player: EntityId) -> f32 {
+        let (affinity, trust) = companion
+            .get_relationship(player)
+            .map_or((0.0, 0.0), |relationship| (relationship.affinity, relationship.trust));
+
+        affinity + Self::LOYALTY_PER_TRUST * trust + Self::LOYALTY_PER_MOOD * companion.get_mood_score()
+    }
+
+    /// Pulls every member out of the registry so they can fight alongside the player
+    pub fn take_members(&self, entities: &mut EntityRegistry) -> Vec<Human> {
+        self.members
+            .iter()
+            .filter_map(|member| match entities.remove(member.id) {
+                Some(RegisteredEntity::Human(human)) => Some(human),
+                Some(entity) => {
+                    entities.insert(entity);
+                    None
+                },
+                None => None
+            })
+            .collect()
+    }
+
+    /// Puts members taken out with `take_members` back into the registry
+    pub fn return_members(&self, entities: &mut EntityRegistry, members: Vec<Human>) {
+        for human in members {
+            entities.insert(RegisteredEntity::Human(human));
+        }
+    }
+}
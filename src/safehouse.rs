@@ -0,0 +1,271 @@
+use serde::{Serialize, Deserialize};
+use std::{collections::BTreeMap, fmt, time::Duration};
+
+use crate::{
+    clock::{self, DayPhase},
+    items::{Inventory, Item},
+    rng::GameRng,
+    util::{random, random_range},
+    world::LocationId
+};
+
+/// Something that can be built inside a safehouse
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum StructureKind {
+    /// Barricades that soften the blow of a raid
+    Fortification,
+    /// Makes resting at the safehouse restore more energy
+    Bed,
+    /// Slowly fills up with drinkable water
+    RainCollector,
+    /// Slowly grows vegetables
+    Garden,
+    /// Speeds up rain collectors and gardens, but its noise draws the undead
+    Generator
+}
+
+impl StructureKind {
+    pub const ALL: [StructureKind; 5] = [
+        StructureKind::Fortification,
+        StructureKind::Bed,
+        StructureKind::RainCollector,
+        StructureKind::Garden,
+        StructureKind::Generator
+    ];
+
+    /// The item ids and counts it takes to build one level
+    pub fn get_cost(&self) -> &'static [(&'static str, u32)] {
+        match self {
+            StructureKind::Fortification => &[("scrap_wood", 6), ("nails", 10), ("scrap_metal", 2)],
+            StructureKind::Bed => &[("scrap_wood", 4), ("cloth", 6)],
+            StructureKind::RainCollector => &[("scrap_metal", 3), ("cloth", 2)],
+            StructureKind::Garden => &[("scrap_wood", 3), ("seeds", 2)],
+            StructureKind::Generator => &[("scrap_metal", 6), ("nails", 6)]
+        }
+    }
+
+    pub fn get_build_duration(&self) -> Duration {
+        match self {
+            StructureKind::Fortification | StructureKind::Generator => clock::hours(3),
+            _ => clock::hours(2)
+        }
+    }
+
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            StructureKind::Fortification => "Lessens the damage of zombie raids",
+            StructureKind::Bed => "Resting here restores more energy",
+            StructureKind::RainCollector => "Collects rainwater over time",
+            StructureKind::Garden => "Grows vegetables over time",
+            StructureKind::Generator => "Speeds up collectors and gardens, but its noise draws the undead"
+        }
+    }
+
+    pub fn get_cost_formatted(&self) -> String {
+        self.get_cost()
+            .iter()
+            .map(|(id, count)| {
+                let name = Item::new(id, 1).map_or(id.to_string(), |item| item.name);
+                format!("{count}x {name}")
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl fmt::Display for StructureKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            StructureKind::Fortification => "Fortification",
+            StructureKind::Bed => "Bed",
+            StructureKind::RainCollector => "Rain Collector",
+            StructureKind::Garden => "Garden",
+            StructureKind::Generator => "Generator"
+        };
+
+        write!(f, "{kind}")
+    }
+}
+
+/// What happened when the undead broke into the safehouse
+#[derive(Debug)]
+pub struct RaidReport {
+    /// Damage left after the fortifications took their share
+    pub damage: f32,
+    /// Items in the stash that were ruined
+    pub lost: Vec<Item>,
+    /// Whether a level of fortifications was torn down
+    pub fortification_broken: bool,
+}
+
+/// What went on at the safehouse while time passed
+#[derive(Debug, Default)]
+pub struct SafehouseReport {
+    /// Water and food added to the stash
+    pub produced: Vec<Item>,
+    pub raid: Option<RaidReport>,
+}
+
+/// A location the player claimed for themselves, with a stash and whatever they built there
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Safehouse {
+    pub location: LocationId,
+    /// Items stored here, separate from what the player carries
+    pub stash: Inventory,
+    /// The level of everything built so far
+    structures: BTreeMap<StructureKind, u32>,
+    /// Hours banked towards the next batch of water and food
+    water_progress: f32,
+    food_progress: f32,
+}
+
+impl Safehouse {
+    /// How many times each structure can be built upon
+    pub const MAX_LEVEL: u32 = 3;
+    /// Chance per hour of a raid at a location of full danger
+    pub const RAID_CHANCE_PER_HOUR: f32 = 0.04;
+    /// The undead are more likely to come knocking at night
+    const NIGHT_RAID_MULTIPLIER: f32 = 2.0;
+    const MIN_RAID_DAMAGE: f32 = 10.0;
+    const MAX_RAID_DAMAGE: f32 = 30.0;
+    /// Share of the raid damage every level of fortifications absorbs
+    const DAMAGE_REDUCTION_PER_FORTIFICATION: f32 = 0.25;
+    /// Chance of any stack in the stash being ruined by a raid at full damage
+    const STASH_LOSS_CHANCE: f32 = 0.5;
+    /// Chance of a raid tearing down a level of fortifications
+    const FORTIFICATION_BREAK_CHANCE: f32 = 0.3;
+    /// Extra energy restored while resting per level of beds
+    const REST_BONUS_PER_BED: f32 = 0.25;
+    /// Hours it takes a single rain collector or garden to produce something
+    const HOURS_PER_WATER: f32 = 8.0;
+    const HOURS_PER_FOOD: f32 = 12.0;
+    /// Extra production per level of generators
+    const PRODUCTION_BONUS_PER_GENERATOR: f32 = 0.5;
+    /// Noise every level of generators makes per hour
+    const NOISE_PER_GENERATOR: f32 = 1.0;
+    const WATER_ITEM: &'static str = "rainwater";
+    const FOOD_ITEM: &'static str = "fresh_vegetables";
+
+    pub fn new(location: LocationId) -> Self {
+        Self {
+            location,
+            stash: Inventory::default(),
+            structures: BTreeMap::new(),
+            water_progress: 0.0,
+            food_progress: 0.0,
+        }
+    }
+
+    pub fn get_level(&self, kind: StructureKind) -> u32 {
+        self.structures.get(&kind).copied().unwrap_or(0)
+    }
+
+    pub fn can_build(&self, kind: StructureKind) -> bool {
+        self.get_level(kind) < Self::MAX_LEVEL
+    }
+
+    /// Adds a level to a structure. Materials are expected to have been paid already
+    pub fn build(&mut self, kind: StructureKind) {
+        let level = self.structures.entry(kind).or_insert(0);
+        *level = (*level + 1).min(Self::MAX_LEVEL);
+    }
+
+    /// Share of raid damage the fortifications absorb
+    pub fn get_damage_reduction(&self) -> f32 {
+        Self::DAMAGE_REDUCTION_PER_FORTIFICATION * self.get_level(StructureKind::Fortification) as f32
+    }
+
+    /// Multiplier on the energy restored while resting here
+    pub fn get_rest_multiplier(&self) -> f32 {
+        1.0 + Self::REST_BONUS_PER_BED * self.get_level(StructureKind::Bed) as f32
+    }
+
+    /// How much noise the safehouse makes per hour, drawing hordes in
+    pub fn get_noise_per_hour(&self) -> f32 {
+        Self::NOISE_PER_GENERATOR * self.get_level(StructureKind::Generator) as f32
+    }
+
+    /// Lets the given amount of in-game time pass. Collectors and gardens fill the stash
+    /// while the undead around may try to break in
+    pub fn update(&mut self, rng: &mut GameRng, elapsed: Duration, danger: f32, day_phase: DayPhase) -> SafehouseReport {
+        let hours = elapsed.as_secs_f32() / 3600.0;
+        let mut report = SafehouseReport::default();
+
+        let production = 1.0 + Self::PRODUCTION_BONUS_PER_GENERATOR * self.get_level(StructureKind::Generator) as f32;
+        let collectors = self.get_level(StructureKind::RainCollector) as f32;
+        let gardens = self.get_level(StructureKind::Garden) as f32;
+        self.water_progress += hours * collectors * production;
+        self.food_progress += hours * gardens * production;
+
+        report.produced.extend(Self::harvest(&mut self.water_progress, Self::HOURS_PER_WATER, Self::WATER_ITEM));
+        report.produced.extend(Self::harvest(&mut self.food_progress, Self::HOURS_PER_FOOD, Self::FOOD_ITEM));
+
+        for item in &report.produced {
+            self.stash.add(item.clone());
+        }
+
+        let mut raid_chance = Self::RAID_CHANCE_PER_HOUR * danger * hours;
+        if day_phase == DayPhase::Night {
+            raid_chance *= Self::NIGHT_RAID_MULTIPLIER;
+        }
+
+        if random(rng) < raid_chance {
+            report.raid = Some(self.raid(rng));
+        }
+
+        report
+    }
+
+    /// Turns banked hours into items
+    fn harvest(progress: &mut f32, hours_per_item: f32, id: &str) -> Option<Item> {
+        let count = (*progress / hours_per_item).floor();
+        if count < 1.0 {
+            return None;
+        }
+
+        *progress -= count * hours_per_item;
+        Item::new(id, count as u32)
+    }
+
+    fn raid(&mut self, rng: &mut GameRng) -> RaidReport {
+        let damage = random_range(rng, Self::MIN_RAID_DAMAGE..Self::MAX_RAID_DAMAGE)
+            * (1.0 - self.get_damage_reduction());
+        let loss_chance = Self::STASH_LOSS_CHANCE * damage / Self::MAX_RAID_DAMAGE;
+
+        let mut lost = Vec::new();
+        let mut index = 0;
+        while index < self.stash.len() {
+            if random(rng) < loss_chance {
+                let count = self.stash.items()[index].count;
+                lost.extend(self.stash.take(index, count));
+            } else {
+                index += 1;
+            }
+        }
+
+        let fortification_broken = self.get_level(StructureKind::Fortification) > 0
+            && random(rng) < Self::FORTIFICATION_BREAK_CHANCE;
+
+        if fortification_broken {
+            if let Some(level) = self.structures.get_mut(&StructureKind::Fortification) {
+                *level -= 1;
+            }
+        }
+
+        RaidReport { damage, lost, fortification_broken }
+    }
+
+    pub fn get_formatted(&self) -> String {
+        let structures = StructureKind::ALL
+            .iter()
+            .map(|kind| format!("{}: {}/{}", kind, self.get_level(*kind), Self::MAX_LEVEL))
+            .collect::<Vec<_>>()
+            .join(" | ");
+
+        format!(
+            "{structures}\nStash: {} stacks ({:.2}kg)",
+            self.stash.len(),
+            self.stash.get_total_weight()
+        )
+    }
+}
@@ -0,0 +1,189 @@
+use serde::{Serialize, Deserialize};
+use std::{cmp::Reverse, fs, io, time::SystemTime};
+
+use crate::{
+    clock,
+    entities::Player,
+    game::Game,
+    util::{file_exists, folder_exists, list_folders, load_from_file, save_to_file}
+};
+
+/// Every save slot lives in a folder of its own in here
+pub const SAVE_FOLDER: &str = "saved";
+
+/// A named game, kept in its own folder so several can exist side by side
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SaveSlot {
+    pub name: String,
+}
+
+impl SaveSlot {
+    const GAME_FILE: &'static str = "game.dat";
+    const PLAYER_FILE: &'static str = "player.dat";
+    const METADATA_FILE: &'static str = "metadata.json";
+    const MAX_NAME_LENGTH: usize = 24;
+
+    /// A slot with the given name. Names have to be safe to use as a folder
+    pub fn new(name: &str) -> Option<Self> {
+        let name = name.trim();
+        let is_valid = !name.is_empty()
+            && name.len() <= Self::MAX_NAME_LENGTH
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, ' ' | '-' | '_'));
+
+        is_valid.then(|| Self { name: name.to_string() })
+    }
+
+    pub fn get_folder(&self) -> String {
+        format!("{}/{}", SAVE_FOLDER, self.name)
+    }
+
+    pub fn get_game_path(&self) -> String {
+        format!("{}/{}", self.get_folder(), Self::GAME_FILE)
+    }
+
+    pub fn get_player_path(&self) -> String {
+        format!("{}/{}", self.get_folder(), Self::PLAYER_FILE)
+    }
+
+    fn get_metadata_path(&self) -> String {
+        format!("{}/{}", self.get_folder(), Self::METADATA_FILE)
+    }
+
+    pub fn exists(&self) -> bool {
+        folder_exists(&self.get_folder())
+    }
+
+    pub fn create(&self) -> Result<(), io::Error> {
+        fs::create_dir_all(self.get_folder())
+    }
+
+    pub fn delete(&self) -> Result<(), io::Error> {
+        fs::remove_dir_all(self.get_folder())
+    }
+
+    /// Reads what is needed to list the slot, without loading the game itself
+    pub fn load_metadata(&self) -> Option<SlotMetadata> {
+        if !file_exists(&self.get_metadata_path()) {
+            return None;
+        }
+
+        let file = load_from_file(&self.get_metadata_path()).ok()?;
+        serde_json::from_str(&file).ok()
+    }
+
+    pub fn save_metadata(&self, metadata: &SlotMetadata) -> Result<(), io::Error> {
+        let serialized = serde_json::to_string_pretty(metadata)
+            .expect("Failed to serialize save metadata");
+
+        save_to_file(&self.get_metadata_path(), &serialized)
+    }
+
+    /// Every save slot, the most recently played first
+    pub fn list() -> Vec<(Self, Option<SlotMetadata>)> {
+        let mut slots = list_folders(SAVE_FOLDER)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|name| Self::new(name))
+            .map(|slot| {
+                let metadata = slot.load_metadata();
+                (slot, metadata)
+            })
+            .collect::<Vec<_>>();
+
+        Self::sort_by_last_played(&mut slots);
+        slots
+    }
+
+    /// Puts the most recently played slots first, and those that can not tell last
+    fn sort_by_last_played(slots: &mut [(Self, Option<SlotMetadata>)]) {
+        slots.sort_by_key(|(_, metadata)| Reverse(metadata.as_ref().map(|metadata| metadata.last_played)));
+    }
+}
+
+/// A summary of a save slot, kept next to it so slots can be listed cheaply
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SlotMetadata {
+    pub character_name: String,
+    /// The in-game time when the slot was last saved
+    pub game_time: SystemTime,
+    pub days_survived: u64,
+    /// The real time when the slot was last saved
+    pub last_played: SystemTime,
+}
+
+impl SlotMetadata {
+    pub fn new(game: &Game, player: &Player) -> Self {
+        Self {
+            character_name: player.human.get_name_formatted(),
+            game_time: game.time,
+            days_survived: game.get_days_survived(),
+            last_played: SystemTime::now(),
+        }
+    }
+
+    pub fn get_formatted(&self) -> String {
+        format!(
+            "{}, Day {} ({}) | Last played {}",
+            self.character_name,
+            self.days_survived + 1,
+            clock::format_time(&self.game_time),
+            clock::format_time(&self.last_played)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::*;
+
+    #[test]
+    fn names_are_trimmed() {
+        assert_eq!(SaveSlot::new("  my game ").unwrap().name, "my game");
+        assert_eq!(SaveSlot::new("run_2-b").unwrap().name, "run_2-b");
+    }
+
+    #[test]
+    fn empty_names_are_rejected() {
+        assert!(SaveSlot::new("").is_none());
+        assert!(SaveSlot::new("   ").is_none());
+    }
+
+    #[test]
+    fn names_can_not_leave_the_save_folder() {
+        for name in ["..", ".", "../game", "a/b", "a\\b", "/etc", "C:game", "~"] {
+            assert!(SaveSlot::new(name).is_none(), "{name} should be rejected");
+        }
+    }
+
+    #[test]
+    fn names_are_capped_in_length() {
+        let longest = "a".repeat(SaveSlot::MAX_NAME_LENGTH);
+
+        assert!(SaveSlot::new(&longest).is_some());
+        assert!(SaveSlot::new(&format!("{longest}a")).is_none());
+    }
+
+    #[test]
+    fn most_recently_played_comes_first() {
+        let metadata = |seconds: u64| SlotMetadata {
+            character_name: String::new(),
+            game_time: UNIX_EPOCH,
+            days_survived: 0,
+            last_played: UNIX_EPOCH + Duration::from_secs(seconds),
+        };
+
+        let mut slots = vec![
+            (SaveSlot::new("old").unwrap(), Some(metadata(10))),
+            (SaveSlot::new("unknown").unwrap(), None),
+            (SaveSlot::new("new").unwrap(), Some(metadata(30))),
+            (SaveSlot::new("middle").unwrap(), Some(metadata(20)))
+        ];
+
+        SaveSlot::sort_by_last_played(&mut slots);
+
+        let names = slots.iter().map(|(slot, _)| slot.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["new", "middle", "old", "unknown"]);
+    }
+}
@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use crate::{
+    clock::{self, DayPhase},
+    entities::{Human, Perk, Zombie},
+    items::{Item, LootTable},
+    rng::GameRng,
+    util::random,
+    world::{Location, LocationId}
+};
+
+/// How long a single scavenging run takes
+pub const SCAVENGE_DURATION: Duration = clock::hours(1);
+/// The most undead that can ambush a scavenger at once
+const MAX_AMBUSH_SIZE: f32 = 2.0;
+
+/// What was found during a scavenging run
+#[derive(Debug, Default)]
+pub struct ScavengeReport {
+    /// Items that made it into the inventory
+    pub found: Vec<Item>,
+    /// Items that were too heavy to carry
+    pub left_behind: Vec<Item>,
+}
+
+impl ScavengeReport {
+    pub fn get_formatted(&self) -> String {
+        if self.found.is_empty() && self.left_behind.is_empty() {
+            return String::from("You found nothing of use.");
+        }
+
+        let mut msg = String::new();
+        for item in &self.found {
+            msg.push_str(&format!("You found {item}\n"));
+        }
+
+        for item in &self.left_behind {
+            msg.push_str(&format!("You had to leave {item} behind, it was too heavy\n"));
+        }
+
+        msg
+    }
+}
+
+/// The chance of running into the undead while scavenging a location
+pub fn get_zombie_chance(location: &Location) -> f32 {
+    LootTable::get(location.kind.get_loot_table_id()).zombie_chance * (0.5 + location.get_danger())
+}
+
+/// Rolls whether the undead ambush a survivor, returning whoever shows up
+pub fn roll_ambush(
+    rng: &mut GameRng,
+    zombie_chance: f32,
+    day_phase: DayPhase,
+    location: LocationId
+) -> Vec<Zombie> {
+    // The undead are more active in the dark
+    let zombie_chance = match day_phase {
+        DayPhase::Night => zombie_chance * 1.5,
+        _ => zombie_chance
+    };
+
+    if random(rng) >= zombie_chance {
+        return Vec::new();
+    }
+
+    let amount = 1 + (random(rng) * MAX_AMBUSH_SIZE) as usize;
+    (0..amount).map(|_| Zombie::new(rng, location)).collect()
+}
+
+/// Searches a location for loot, picking it a little cleaner
+pub fn scavenge(rng: &mut GameRng, human: &mut Human, location: &mut Location) -> ScavengeReport {
+    let mut report = ScavengeReport::default();
+    let loot_table = LootTable::get(location.kind.get_loot_table_id());
+
+    // A trained eye spots what others walked right past
+    let richness = if human.has_perk(Perk::ScavengersEye) {
+        (location.richness + Perk::SCAVENGERS_EYE_RICHNESS).min(1.0)
+    } else {
+        location.richness
+    };
+
+    let found = loot_table.roll(rng, &human.get_attributes(), richness);
+    location.richness = (location.richness - Location::RICHNESS_PER_SCAVENGE).max(0.0);
+
+    for item in found {
+        match human.pick_up(item.clone()) {
+            Ok(()) => report.found.push(item),
+            Err(item) => report.left_behind.push(*item)
+        }
+    }
+
+    report
+}
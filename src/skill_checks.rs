@@ -0,0 +1,92 @@
+use std::fmt;
+
+use crate::{
+    entities::{Human, Perk},
+    rng::GameRng,
+    util::random
+};
+
+const MIN_CHANCE: f32 = 0.05;
+const MAX_CHANCE: f32 = 0.95;
+const CHANCE_PER_CHARISMA: f32 = 0.06;
+/// How much the target's mood, from -1 to 1, sways a check
+const CHANCE_PER_MOOD: f32 = 0.15;
+const CHANCE_PER_AFFINITY: f32 = 0.003;
+const CHANCE_PER_TRUST: f32 = 0.003;
+/// Intimidation leans on looking the part
+const INTIMIDATE_CHANCE_PER_STRENGTH: f32 = 0.04;
+const INTIMIDATE_CHANCE_PER_TARGET_COMBAT: f32 = 0.05;
+/// Sharp targets see through lies
+const LIE_CHANCE_PER_TARGET_INTELLIGENCE: f32 = 0.04;
+
+/// A way of getting what you want out of somebody by talking
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillCheck {
+    /// Bringing somebody around to your point of view
+    Persuade,
+    /// Haggling over a price
+    Barter,
+    /// Scaring somebody into doing what you want
+    Intimidate,
+    /// Getting somebody to believe something that is not true
+    Lie
+}
+
+impl SkillCheck {
+    /// The chance of the player pulling this off against a target.
+    /// Charisma always helps, the rest depends on how the target feels about them
+    pub fn get_chance(&self, player: &Human, target: &Human) -> f32 {
+        let relationship = target.get_relationship(player.id);
+        let affinity = relationship.map_or(0.0, |relationship| relationship.affinity);
+        let trust = relationship.map_or(0.0, |relationship| relationship.trust);
+        let charisma = CHANCE_PER_CHARISMA * player.get_charisma();
+        let mood = CHANCE_PER_MOOD * target.get_mood_score();
+
+        let chance = match self {
+            SkillCheck::Persuade => 0.2 + charisma + mood + CHANCE_PER_AFFINITY * affinity,
+            SkillCheck::Barter => 0.25 + charisma + mood + CHANCE_PER_AFFINITY * affinity * 0.5,
+            // Those already rattled are easier to push around, friends do not scare easily
+            SkillCheck::Intimidate => {
+                0.25 + charisma * 0.5 - mood - CHANCE_PER_AFFINITY * affinity
+                    + INTIMIDATE_CHANCE_PER_STRENGTH * player.get_attributes().strength as f32
+                    - INTIMIDATE_CHANCE_PER_TARGET_COMBAT * target.get_attributes().combat as f32
+            },
+            SkillCheck::Lie => {
+                0.3 + charisma + CHANCE_PER_TRUST * trust
+                    - LIE_CHANCE_PER_TARGET_INTELLIGENCE * target.get_attributes().intelligence as f32
+            }
+        };
+
+        // A way with words helps with anything but threats
+        let chance = if player.has_perk(Perk::SilverTongue) && *self != SkillCheck::Intimidate {
+            chance + Perk::SILVER_TONGUE_CHANCE
+        } else {
+            chance
+        };
+
+        chance.clamp(MIN_CHANCE, MAX_CHANCE)
+    }
+
+    /// Rolls the check. Returns whether the player succeeded
+    pub fn roll(&self, rng: &mut GameRng, player: &Human, target: &Human) -> bool {
+        random(rng) < self.get_chance(player, target)
+    }
+
+    /// The check as a menu option, showing the odds
+    pub fn get_option_formatted(&self, text: &str, player: &Human, target: &Human) -> String {
+        format!("[{} {:.0}%] {}", self, self.get_chance(player, target) * 100.0, text)
+    }
+}
+
+impl fmt::Display for SkillCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let check = match self {
+            SkillCheck::Persuade => "Persuade",
+            SkillCheck::Barter => "Barter",
+            SkillCheck::Intimidate => "Intimidate",
+            SkillCheck::Lie => "Lie"
+        };
+
+        write!(f, "{check}")
+    }
+}
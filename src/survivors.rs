@@ -0,0 +1,175 @@
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+use crate::{
+    entities::{Emotion, EntityId, Human, Relationship},
+    items::{Item, LootTable},
+    rng::GameRng,
+    util::{random, random_choice, random_range},
+    world::Location
+};
+
+/// Chance of crossing paths with another survivor when arriving somewhere safe
+const BASE_SURVIVOR_CHANCE: f32 = 0.3;
+/// Chance of every extra survivor tagging along with a group, and the most a group can have
+const GROUP_CHANCE: f32 = 0.3;
+const MAX_GROUP_SIZE: usize = 3;
+/// Chance of a survivor carrying a weapon
+const ARMED_CHANCE: f32 = 0.45;
+/// Charisma of an unremarkable survivor, above which they are easier to get along with
+const AVERAGE_CHARISMA: f32 = 2.5;
+/// How much each part of a survivor's outlook weighs on their attitude
+const ATTITUDE_PER_CHARISMA: f32 = 6.0;
+const ATTITUDE_PER_MOOD: f32 = 30.0;
+const ATTITUDE_PER_REPUTATION: f32 = 0.4;
+const ATTITUDE_PER_AFFINITY: f32 = 0.5;
+
+/// How a survivor feels about the player when they cross paths
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Attitude {
+    Hostile,
+    Wary,
+    Neutral,
+    Friendly
+}
+
+impl Attitude {
+    /// How much more a survivor asks for their goods
+    pub fn get_price_multiplier(&self) -> f32 {
+        match self {
+            Attitude::Hostile => f32::INFINITY,
+            Attitude::Wary => 1.5,
+            Attitude::Neutral => 1.2,
+            Attitude::Friendly => 1.0
+        }
+    }
+
+    /// The chance of a survivor agreeing to join up before trust and charisma
+    pub fn get_recruit_chance(&self) -> f32 {
+        match self {
+            Attitude::Hostile => 0.0,
+            Attitude::Wary => 0.05,
+            Attitude::Neutral => 0.2,
+            Attitude::Friendly => 0.45
+        }
+    }
+}
+
+impl fmt::Display for Attitude {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let attitude = match self {
+            Attitude::Hostile => "Hostile",
+            Attitude::Wary => "Wary",
+            Attitude::Neutral => "Neutral",
+            Attitude::Friendly => "Friendly"
+        };
+
+        write!(f, "{attitude}")
+    }
+}
+
+/// Works out how a survivor feels about the player from their charisma,
+/// their mood, what they have heard of the player and what has happened between them
+pub fn get_attitude(survivor: &Human, player: EntityId, reputation: f32) -> Attitude {
+    let charisma = survivor.get_charisma() - AVERAGE_CHARISMA;
+    let affinity = survivor.get_relationship(player).map_or(0.0, |relationship| relationship.affinity);
+
+    let score = ATTITUDE_PER_CHARISMA * charisma
+        + ATTITUDE_PER_MOOD * survivor.get_mood_score()
+        + ATTITUDE_PER_REPUTATION * reputation
+        + ATTITUDE_PER_AFFINITY * affinity;
+
+    match score {
+        score if score < -20.0 => Attitude::Hostile,
+        score if score < 0.0 => Attitude::Wary,
+        score if score < 20.0 => Attitude::Neutral,
+        _ => Attitude::Friendly
+    }
+}
+
+/// Rolls whether a group of survivors shows up at a location. Dangerous places are left to the undead.
+/// Members of a group knew each other before the outbreak
+pub fn roll_survivors(rng: &mut GameRng, location: &Location) -> Vec<Human> {
+    let chance = BASE_SURVIVOR_CHANCE * (1.0 - location.get_danger() * 0.5);
+    if random(rng) >= chance {
+        return Vec::new();
+    }
+
+    let mut group = vec![spawn_survivor(rng, location)];
+    while group.len() < MAX_GROUP_SIZE && random(rng) < GROUP_CHANCE {
+        group.push(spawn_survivor(rng, location));
+    }
+
+    for i in 0..group.len() {
+        for j in i + 1..group.len() {
+            let (left, right) = group.split_at_mut(j);
+            introduce(rng, &mut left[i], &mut right[0]);
+        }
+    }
+
+    group
+}
+
+/// Rolls how two survivors knew each other, the same way from both sides
+fn introduce(rng: &mut GameRng, a: &mut Human, b: &mut Human) {
+    let relationship = Relationship::generate(rng, b.id);
+    let mut mirrored = relationship.clone();
+    mirrored.target = a.id;
+
+    a.relationships.push(relationship);
+    b.relationships.push(mirrored);
+}
+
+/// Creates a survivor at a location, carrying whatever they scrounged up around there
+pub fn spawn_survivor(rng: &mut GameRng, location: &Location) -> Human {
+    let mut survivor = Human::new(rng);
+    survivor.current_location = location.id;
+    survivor.age = random_range(rng, 17.0..=65.0) as u8;
+
+    let loot_table = LootTable::get(location.kind.get_loot_table_id());
+    for item in loot_table.roll(rng, &survivor.attributes, 1.0) {
+        survivor.inventory.add(item);
+    }
+
+    if random(rng) < ARMED_CHANCE {
+        let weapons = Item::definitions()
+            .iter()
+            .filter(|item| item.is_weapon())
+            .collect::<Vec<_>>();
+
+        if let Some(weapon) = random_choice(rng, &weapons) {
+            survivor.weapon = Item::new(&weapon.id, 1);
+        }
+    }
+
+    let intensity = random_range(rng, 0.2..0.8);
+    let emotion = match (random(rng) * 4.0) as u8 {
+        0 => Emotion::Happinness(intensity),
+        1 => Emotion::Fear(intensity),
+        2 => Emotion::Anger(intensity),
+        _ => Emotion::Neutral
+    };
+    survivor.feel(emotion);
+
+    survivor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn introductions_are_mutual() {
+        let mut rng = GameRng::new(3);
+        let mut a = Human::new(&mut rng);
+        let mut b = Human::new(&mut rng);
+
+        introduce(&mut rng, &mut a, &mut b);
+
+        let from_a = a.get_relationship(b.id).unwrap();
+        let from_b = b.get_relationship(a.id).unwrap();
+        assert_eq!(from_a.kind, from_b.kind);
+        assert_eq!(from_a.affinity, from_b.affinity);
+        assert_eq!(from_a.trust, from_b.trust);
+    }
+}
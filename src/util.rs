@@ -6,9 +6,11 @@ use std::{
     path::Path,
     str::FromStr,
     thread,
-    time::{SystemTime, Duration}
+    time::Duration
 };
 
+use crate::rng::GameRng;
+
 pub fn collect_input_str(prompt: Option<&str>) -> Result<String, io::Error> {
     collect_input::<String>(prompt)
 }
@@ -60,51 +62,61 @@ pub fn collect_with_options(text: &str, options: &[&str]) -> Result<usize, &'sta
     collect_with_options(text, options)
 }
 
-pub fn random() -> f32 {
-    // Create a seed from UNIX EPOCH until now in nanoseconds
-    let seed = SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .expect("Time went backwards")
-        .as_nanos()
-        // Reversing the bits of the seed can help improve randomness.
-        // This is particularly useful when the seed is derived from time,
-        // as it helps to disperse the bits and avoid patterns related to
-        // the system's clock.
-        .reverse_bits();
-
-    // Reduce the size of the seed to avoid overflow
-    // This technically reduces our randomness but by a negligible amount
-    let seed = seed / 10e+28_f64 as u128;
-    // Divide by the closest maximum value depending on the amount of digits
-    let max = 10u128.pow(seed.to_string().len() as u32) - 1;
-
-    seed as f32 / max as f32
+/// Returns a random scalar within 0.0..1.0 drawn from the game's generator
+pub fn random(rng: &mut GameRng) -> f32 {
+    rng.next_f32()
 }
 
 // Returns a random number from within a range
-pub fn random_range<T>(range: T) -> f32
+pub fn random_range<T>(rng: &mut GameRng, range: T) -> f32
 where
     T: RangeBounds<f32>
 {
     // Our randomly generated scalar (0.0 - 1.0)
-    let r = random();
+    let r = random(rng);
 
     // Extract the inner f32 from the ranges, whether theyre inclusive or not
     let min = match range.start_bound() {
-        Bound::Included(&x) => x.into(),
-        Bound::Excluded(&x) => x.into(),
+        Bound::Included(&x) => x,
+        Bound::Excluded(&x) => x,
         Bound::Unbounded => f32::MIN,
     };
 
     let max = match range.end_bound() {
-        Bound::Included(&x) => x.into(),
-        Bound::Excluded(&x) => x.into(),
+        Bound::Included(&x) => x,
+        Bound::Excluded(&x) => x,
         Bound::Unbounded => f32::MAX,
     };
 
     min + (r * (max - min))
 }
 
+/// Picks an element at random
+pub fn random_choice<'a, T>(rng: &mut GameRng, options: &'a [T]) -> Option<&'a T> {
+    let index = (random(rng) * options.len() as f32) as usize;
+    options.get(index.min(options.len().saturating_sub(1)))
+}
+
+/// Picks an index at random where each index is as likely as its weight
+pub fn random_weighted_index(rng: &mut GameRng, weights: &[f32]) -> Option<usize> {
+    let total: f32 = weights.iter().sum();
+    if total <= 0.0 {
+        return None;
+    }
+
+    let mut roll = random_range(rng, 0.0..total);
+    for (i, weight) in weights.iter().enumerate() {
+        if roll < *weight {
+            return Some(i);
+        }
+
+        roll -= weight;
+    }
+
+    // Floating point error can leave a tiny remainder
+    weights.iter().rposition(|weight| *weight > 0.0)
+}
+
 pub fn read_file_lines(file_path: &str) -> Result<Vec<String>, io::Error> {
     let file = File::open(file_path)?;
     let reader = io::BufReader::new(file);
@@ -131,6 +143,31 @@ pub fn file_exists(file_path: &str) -> bool {
     }
 }
 
+/// Lists the files in a folder with the given extension, sorted by path
+pub fn list_files(folder_path: &str, extension: &str) -> Result<Vec<String>, io::Error> {
+    let mut files = fs::read_dir(folder_path)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == extension))
+        .filter_map(|path| path.to_str().map(String::from))
+        .collect::<Vec<_>>();
+
+    files.sort();
+    Ok(files)
+}
+
+/// Lists the names of the folders inside a folder, sorted by name
+pub fn list_folders(folder_path: &str) -> Result<Vec<String>, io::Error> {
+    let mut folders = fs::read_dir(folder_path)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().to_str().map(String::from))
+        .collect::<Vec<_>>();
+
+    folders.sort();
+    Ok(folders)
+}
+
 /// Loads the contents of a file as a string
 pub fn load_from_file(file_path: &str) -> Result<String, io::Error> {
     if !file_exists(file_path) {
@@ -0,0 +1,238 @@
+use serde::{Serialize, Deserialize};
+
+use crate::{
+    rng::GameRng,
+    util::{load_from_file, random, random_choice, random_range, random_weighted_index}
+};
+use super::{Location, LocationId, LocationKind, Route, World};
+
+const CITY_FILEPATH: &str = "./data/city.json";
+/// Mixed into the game seed so the city does not share rolls with anything else
+const WORLD_SEED_SALT: u64 = 0x4E59_4349_5459;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct BoroughTemplate {
+    name: String,
+    danger: f32,
+    neighbourhoods: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct BridgeTemplate {
+    from: String,
+    to: String,
+    distance: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct BuildingTemplate {
+    kind: LocationKind,
+    weight: f32,
+    /// Added on top of the danger of the borough
+    danger: f32,
+    richness: [f32; 2],
+    /// Names with `{street}` and `{neighbourhood}` placeholders
+    names: Vec<String>,
+}
+
+/// The building blocks the city is generated from, loaded from `data/city.json`
+#[derive(Serialize, Deserialize, Debug)]
+struct CityTemplate {
+    neighbourhoods_per_borough: [u32; 2],
+    buildings_per_neighbourhood: [u32; 2],
+    boroughs: Vec<BoroughTemplate>,
+    bridges: Vec<BridgeTemplate>,
+    streets: Vec<String>,
+    buildings: Vec<BuildingTemplate>,
+}
+
+impl CityTemplate {
+    fn load() -> Self {
+        let file = load_from_file(CITY_FILEPATH)
+            .expect("Failed to load city file");
+
+        serde_json::from_str(&file)
+            .expect("Failed to parse city file")
+    }
+}
+
+fn random_count(rng: &mut GameRng, [min, max]: [u32; 2]) -> u32 {
+    random_range(rng, min as f32..(max + 1) as f32) as u32
+}
+
+/// Picks `amount` distinct entries from a list
+fn pick_distinct<T: Clone>(rng: &mut GameRng, options: &[T], amount: usize) -> Vec<T> {
+    let mut remaining = options.to_vec();
+    let mut picked = Vec::new();
+
+    while picked.len() < amount && !remaining.is_empty() {
+        let index = ((random(rng) * remaining.len() as f32) as usize).min(remaining.len() - 1);
+        picked.push(remaining.remove(index));
+    }
+
+    picked
+}
+
+/// Builds the world one location at a time
+struct CityBuilder {
+    world: World,
+}
+
+impl CityBuilder {
+    fn add_location(&mut self, mut location: Location) -> LocationId {
+        let id = LocationId(self.world.locations.len() as u32);
+        location.id = id;
+        self.world.locations.push(location);
+        id
+    }
+
+    fn add_route(&mut self, from: LocationId, to: LocationId, distance: f32) {
+        self.world.routes.push(Route { from, to, distance });
+    }
+
+    fn has_name(&self, name: &str) -> bool {
+        self.world.locations.iter().any(|location| location.name == name)
+    }
+}
+
+impl World {
+    /// Generates the city from a seed. The same seed always produces the same city
+    pub fn generate(seed: u64) -> Self {
+        let template = CityTemplate::load();
+        let rng = &mut GameRng::new(seed ^ WORLD_SEED_SALT);
+        let mut builder = CityBuilder { world: World::default() };
+
+        // The street hubs of every neighbourhood, grouped by borough
+        let mut borough_hubs = Vec::new();
+
+        for borough in &template.boroughs {
+            let amount = random_count(rng, template.neighbourhoods_per_borough) as usize;
+            let neighbourhoods = pick_distinct(rng, &borough.neighbourhoods, amount);
+            let mut hubs = Vec::new();
+
+            for neighbourhood in neighbourhoods {
+                let hub = builder.add_location(Location {
+                    id: LocationId::default(),
+                    name: format!("{} Streets", neighbourhood),
+                    borough: borough.name.clone(),
+                    neighbourhood: neighbourhood.clone(),
+                    kind: LocationKind::Street,
+                    danger: (borough.danger + random_range(rng, -0.1..0.1)).clamp(0.0, 1.0),
+                    horde_danger: 0.0,
+                    richness: random_range(rng, 0.3..0.5),
+                    interior: None,
+                });
+
+                let weights = template.buildings.iter().map(|building| building.weight).collect::<Vec<_>>();
+                for _ in 0..random_count(rng, template.buildings_per_neighbourhood) {
+                    let Some(index) = random_weighted_index(rng, &weights) else {
+                        break;
+                    };
+
+                    let building = &template.buildings[index];
+                    let name_template = random_choice(rng, &building.names).cloned().unwrap_or_default();
+                    let street = random_choice(rng, &template.streets).cloned().unwrap_or_default();
+                    let name = name_template
+                        .replace("{street}", &street)
+                        .replace("{neighbourhood}", &neighbourhood);
+
+                    // Two identical buildings in the same city would be confusing
+                    if builder.has_name(&name) {
+                        continue;
+                    }
+
+                    let [min_richness, max_richness] = building.richness;
+                    let location = builder.add_location(Location {
+                        id: LocationId::default(),
+                        name,
+                        borough: borough.name.clone(),
+                        neighbourhood: neighbourhood.clone(),
+                        kind: building.kind,
+                        danger: (borough.danger + building.danger + random_range(rng, -0.15..0.15)).clamp(0.0, 1.0),
+                        horde_danger: 0.0,
+                        richness: random_range(rng, min_richness..max_richness),
+                        interior: None,
+                    });
+
+                    let distance = random_range(rng, 0.1..0.5);
+                    builder.add_route(hub, location, distance);
+                }
+
+                hubs.push(hub);
+            }
+
+            // Neighbourhoods of a borough are chained together, with the odd shortcut
+            for pair in hubs.windows(2) {
+                let distance = random_range(rng, 1.5..4.5);
+                builder.add_route(pair[0], pair[1], distance);
+            }
+
+            if hubs.len() > 2 && random(rng) < 0.5 {
+                let distance = random_range(rng, 3.0..6.0);
+                builder.add_route(hubs[0], hubs[hubs.len() - 1], distance);
+            }
+
+            borough_hubs.push((borough.name.clone(), hubs));
+        }
+
+        for bridge in &template.bridges {
+            let find_hubs = |name: &str| {
+                borough_hubs
+                    .iter()
+                    .find(|(borough, _)| borough == name)
+                    .map(|(_, hubs)| hubs.clone())
+                    .unwrap_or_default()
+            };
+
+            let (from, to) = (find_hubs(&bridge.from), find_hubs(&bridge.to));
+            if let (Some(from), Some(to)) = (random_choice(rng, &from).copied(), random_choice(rng, &to).copied()) {
+                let distance = bridge.distance * random_range(rng, 0.8..1.2);
+                builder.add_route(from, to, distance);
+            }
+        }
+
+        // Survivors wake up at home
+        let mut world = builder.world;
+        world.seed = seed;
+        world.start = world.locations
+            .iter()
+            .find(|location| location.kind == LocationKind::Apartment)
+            .map_or(LocationId::default(), |location| location.id);
+
+        world.spawn_hordes(rng);
+        world
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_world() {
+        let a = World::generate(1234);
+        let b = World::generate(1234);
+
+        assert_eq!(a.start, b.start);
+        assert_eq!(a.locations().len(), b.locations().len());
+
+        for (a, b) in a.locations().iter().zip(b.locations()) {
+            assert_eq!(a.name, b.name);
+            assert_eq!(a.kind, b.kind);
+            assert_eq!(a.danger, b.danger);
+            assert_eq!(a.richness, b.richness);
+        }
+
+        let hordes = |world: &World| world.hordes().iter().map(|horde| (horde.location, horde.size)).collect::<Vec<_>>();
+        assert_eq!(hordes(&a), hordes(&b));
+        assert_eq!(serde_json::to_string(&a).unwrap(), serde_json::to_string(&b).unwrap());
+    }
+
+    #[test]
+    fn different_seeds_different_worlds() {
+        let a = World::generate(1);
+        let b = World::generate(2);
+
+        assert_ne!(serde_json::to_string(&a).unwrap(), serde_json::to_string(&b).unwrap());
+    }
+}
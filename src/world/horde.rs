@@ -0,0 +1,234 @@
+use serde::{Serialize, Deserialize};
+use std::time::Duration;
+
+use crate::{
+    clock::DayPhase,
+    rng::GameRng,
+    util::{random, random_range, random_weighted_index}
+};
+use super::{LocationId, World};
+
+/// A group of undead roaming the city together
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Horde {
+    pub location: LocationId,
+    pub size: u32,
+}
+
+impl Horde {
+    /// Danger added to a location for every member of a horde in it
+    const DANGER_PER_ZOMBIE: f32 = 0.015;
+    /// The most danger a single horde can add to a location
+    const MAX_DANGER: f32 = 0.6;
+
+    pub fn get_danger(&self) -> f32 {
+        (Self::DANGER_PER_ZOMBIE * self.size as f32).min(Self::MAX_DANGER)
+    }
+
+    /// How big the horde looks from a distance
+    pub fn get_size_formatted(&self) -> &'static str {
+        match self.size {
+            0..=4 => "A few stragglers",
+            5..=14 => "A pack of undead",
+            15..=39 => "A horde",
+            _ => "A massive horde"
+        }
+    }
+}
+
+/// A horde making its way from one location to another
+#[derive(Debug, Clone)]
+pub struct HordeMovement {
+    pub from: LocationId,
+    /// The horde as it arrived at its new location
+    pub horde: Horde,
+}
+
+impl World {
+    /// One horde roams the city for every this many locations
+    const LOCATIONS_PER_HORDE: usize = 6;
+    const MIN_HORDE_SIZE: f32 = 5.0;
+    const MAX_HORDE_SIZE: f32 = 30.0;
+    /// Chance per hour of a horde moving on
+    const HORDE_MOVE_CHANCE_PER_HOUR: f32 = 0.15;
+    /// The undead roam far more in the dark
+    const NIGHT_MOVE_MULTIPLIER: f32 = 2.5;
+    /// How strongly noise draws hordes in compared to wandering aimlessly
+    const NOISE_ATTRACTION: f32 = 0.5;
+    /// Share of the noise at a location that dies down every hour
+    const NOISE_DECAY_PER_HOUR: f32 = 0.5;
+    /// Noise quieter than this is forgotten about
+    const MIN_NOISE: f32 = 0.1;
+
+    pub fn hordes(&self) -> &[Horde] {
+        &self.hordes
+    }
+
+    pub fn hordes_at(&self, location: LocationId) -> impl Iterator<Item = &Horde> {
+        self.hordes.iter().filter(move |horde| horde.location == location)
+    }
+
+    /// How loud it has been at a location lately
+    pub fn get_noise(&self, location: LocationId) -> f32 {
+        self.noise.get(&location).copied().unwrap_or(0.0)
+    }
+
+    /// Draws the attention of hordes nearby
+    pub fn make_noise(&mut self, location: LocationId, amount: f32) {
+        *self.noise.entry(location).or_insert(0.0) += amount;
+    }
+
+    /// Takes undead that were put down out of the hordes at a location
+    pub fn thin_hordes(&mut self, location: LocationId, mut killed: u32) {
+        for horde in self.hordes.iter_mut().filter(|horde| horde.location == location) {
+            let taken = killed.min(horde.size);
+            horde.size -= taken;
+            killed -= taken;
+        }
+
+        self.hordes.retain(|horde| horde.size > 0);
+        self.update_horde_danger();
+    }
+
+    /// Scatters the first hordes around the city, favouring the most dangerous places.
+    /// Nobody starts out with a horde on their doorstep
+    pub(super) fn spawn_hordes(&mut self, rng: &mut GameRng) {
+        let amount = self.locations.len() / Self::LOCATIONS_PER_HORDE;
+        let weights = self.locations
+            .iter()
+            .map(|location| if location.id == self.start { 0.0 } else { location.danger })
+            .collect::<Vec<_>>();
+
+        for _ in 0..amount {
+            let Some(index) = random_weighted_index(rng, &weights) else {
+                break;
+            };
+
+            let size = random_range(rng, Self::MIN_HORDE_SIZE..Self::MAX_HORDE_SIZE) as u32;
+            self.hordes.push(Horde { location: self.locations[index].id, size });
+        }
+
+        self.merge_hordes();
+        self.update_horde_danger();
+    }
+
+    /// Moves hordes around the city, drawn in by noise and roaming more at night.
+    /// Returns every horde that moved
+    pub fn update_hordes(&mut self, rng: &mut GameRng, elapsed: Duration, day_phase: DayPhase) -> Vec<HordeMovement> {
+        let hours = elapsed.as_secs_f32() / 3600.0;
+        let mut movements = Vec::new();
+
+        let mut move_chance = Self::HORDE_MOVE_CHANCE_PER_HOUR * hours;
+        if day_phase == DayPhase::Night {
+            move_chance *= Self::NIGHT_MOVE_MULTIPLIER;
+        }
+
+        for index in 0..self.hordes.len() {
+            if random(rng) >= move_chance {
+                continue;
+            }
+
+            // Staying put is an option too, loud places are hard to leave
+            let from = self.hordes[index].location;
+            let mut options = vec![from];
+            options.extend(self.get_neighbours(from).into_iter().map(|(id, _)| id));
+
+            let weights = options
+                .iter()
+                .map(|id| 1.0 + Self::NOISE_ATTRACTION * self.get_noise(*id))
+                .collect::<Vec<_>>();
+
+            let to = random_weighted_index(rng, &weights).map_or(from, |index| options[index]);
+            if to != from {
+                self.hordes[index].location = to;
+                movements.push(HordeMovement { from, horde: self.hordes[index].clone() });
+            }
+        }
+
+        let decay = (1.0 - Self::NOISE_DECAY_PER_HOUR).powf(hours);
+        for noise in self.noise.values_mut() {
+            *noise *= decay;
+        }
+
+        self.noise.retain(|_, noise| *noise >= Self::MIN_NOISE);
+        self.merge_hordes();
+        self.update_horde_danger();
+
+        movements
+    }
+
+    /// Hordes that end up in the same place move on as one
+    fn merge_hordes(&mut self) {
+        let mut merged: Vec<Horde> = Vec::new();
+
+        for horde in self.hordes.drain(..) {
+            match merged.iter_mut().find(|other| other.location == horde.location) {
+                Some(other) => other.size += horde.size,
+                None => merged.push(horde)
+            }
+        }
+
+        self.hordes = merged;
+    }
+
+    fn update_horde_danger(&mut self) {
+        for location in self.locations.iter_mut() {
+            location.horde_danger = 0.0;
+        }
+
+        for horde in &self.hordes {
+            if let Some(location) = self.locations.get_mut(horde.location.0 as usize) {
+                location.horde_danger += horde.get_danger();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn world_with(hordes: Vec<Horde>) -> World {
+        let mut world = World::generate(1);
+        world.hordes = hordes;
+        world
+    }
+
+    #[test]
+    fn thin_hordes_takes_kills_across_hordes() {
+        let mut world = world_with(vec![
+            Horde { location: LocationId(1), size: 4 },
+            Horde { location: LocationId(1), size: 6 },
+            Horde { location: LocationId(2), size: 5 }
+        ]);
+
+        world.thin_hordes(LocationId(1), 7);
+
+        let sizes = world.hordes().iter().map(|horde| (horde.location, horde.size)).collect::<Vec<_>>();
+        assert_eq!(sizes, vec![(LocationId(1), 3), (LocationId(2), 5)]);
+    }
+
+    #[test]
+    fn thin_hordes_drops_empty_hordes() {
+        let mut world = world_with(vec![Horde { location: LocationId(1), size: 4 }]);
+
+        world.thin_hordes(LocationId(1), 10);
+
+        assert!(world.hordes().is_empty());
+        assert_eq!(world.location(LocationId(1)).horde_danger, 0.0);
+    }
+
+    #[test]
+    fn merge_hordes_sums_sizes_per_location() {
+        let mut world = world_with(vec![
+            Horde { location: LocationId(1), size: 4 },
+            Horde { location: LocationId(2), size: 5 },
+            Horde { location: LocationId(1), size: 6 }
+        ]);
+
+        world.merge_hordes();
+
+        let sizes = world.hordes().iter().map(|horde| (horde.location, horde.size)).collect::<Vec<_>>();
+        assert_eq!(sizes, vec![(LocationId(1), 10), (LocationId(2), 5)]);
+    }
+}
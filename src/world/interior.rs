@@ -0,0 +1,145 @@
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+use crate::{
+    entities::EntityAttributes,
+    items::{Inventory, LootTable},
+    rng::GameRng,
+    util::{random, random_range}
+};
+use super::Location;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoomKind {
+    Lobby,
+    Storage,
+    Rooftop,
+    Basement
+}
+
+impl RoomKind {
+    /// Chance of the door to this room being locked
+    fn get_lock_chance(&self) -> f32 {
+        match self {
+            RoomKind::Lobby => 0.0,
+            RoomKind::Storage => 0.6,
+            RoomKind::Rooftop => 0.4,
+            RoomKind::Basement => 0.3
+        }
+    }
+
+    /// How much more likely the undead are to lie dormant here
+    fn get_zombie_multiplier(&self) -> f32 {
+        match self {
+            RoomKind::Lobby => 0.8,
+            RoomKind::Storage => 0.6,
+            RoomKind::Rooftop => 0.3,
+            RoomKind::Basement => 1.5
+        }
+    }
+}
+
+impl fmt::Display for RoomKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            RoomKind::Lobby => "Lobby",
+            RoomKind::Storage => "Storage",
+            RoomKind::Rooftop => "Rooftop",
+            RoomKind::Basement => "Basement"
+        };
+
+        write!(f, "{kind}")
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Room {
+    pub kind: RoomKind,
+    /// How hard the door is to force open, if it is locked at all
+    pub lock: Option<u8>,
+    /// Whatever is stashed away in the room
+    pub container: Inventory,
+    /// Undead sleeping in the room until something wakes them
+    pub dormant_zombies: u32,
+    pub explored: bool,
+}
+
+impl Room {
+    pub fn get_formatted(&self) -> String {
+        let status = if self.lock.is_some() {
+            "Locked"
+        } else if !self.explored {
+            "Unexplored"
+        } else if self.container.is_empty() {
+            "Empty"
+        } else {
+            "Explored"
+        };
+
+        format!("{} ({})", self.kind, status)
+    }
+}
+
+/// The rooms inside a building, generated the first time anybody enters it
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Interior {
+    pub rooms: Vec<Room>,
+}
+
+impl Interior {
+    /// Lock difficulty is rolled within this range, compared against strength
+    const LOCK_DIFFICULTY: [f32; 2] = [3.0, 9.0];
+    /// Chance of a room having dormant undead at full danger
+    const ZOMBIE_CHANCE: f32 = 0.5;
+    /// Share of a building's loot table rolls every room gets
+    const ROOM_ROLLS: f32 = 0.5;
+
+    pub fn generate(rng: &mut GameRng, location: &Location) -> Self {
+        let mut kinds = vec![RoomKind::Lobby, RoomKind::Storage];
+
+        if random(rng) < 0.6 {
+            kinds.push(RoomKind::Basement);
+        }
+
+        if random(rng) < 0.5 {
+            kinds.push(RoomKind::Rooftop);
+        }
+
+        let loot_table = LootTable::get(location.kind.get_loot_table_id());
+        let attributes = EntityAttributes::default();
+
+        let rooms = kinds
+            .into_iter()
+            .map(|kind| {
+                let lock = (random(rng) < kind.get_lock_chance()).then(|| {
+                    let [min, max] = Self::LOCK_DIFFICULTY;
+                    random_range(rng, min..max) as u8
+                });
+
+                let mut container = Inventory::default();
+                if random(rng) < Self::ROOM_ROLLS + 0.5 * location.richness {
+                    for item in loot_table.roll(rng, &attributes, location.richness) {
+                        container.add(item);
+                    }
+                }
+
+                let zombie_chance = Self::ZOMBIE_CHANCE * location.get_danger() * kind.get_zombie_multiplier();
+                let dormant_zombies = if random(rng) < zombie_chance {
+                    1 + (random(rng) * 2.0) as u32
+                } else {
+                    0
+                };
+
+                Room {
+                    kind,
+                    lock,
+                    container,
+                    dormant_zombies,
+                    explored: false,
+                }
+            })
+            .collect();
+
+        Self { rooms }
+    }
+}
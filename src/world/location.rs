@@ -0,0 +1,121 @@
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+use super::Interior;
+
+/// Refers to a location in the world by its index
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct LocationId(pub u32);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocationKind {
+    Street,
+    Park,
+    Apartment,
+    Grocery,
+    Pharmacy,
+    Hospital,
+    PoliceStation,
+    HardwareStore
+}
+
+impl LocationKind {
+    /// The id of the loot table scavenged at this kind of location
+    pub fn get_loot_table_id(&self) -> &'static str {
+        match self {
+            LocationKind::Street => "street",
+            LocationKind::Park => "park",
+            LocationKind::Apartment => "apartment",
+            LocationKind::Grocery => "grocery",
+            LocationKind::Pharmacy => "pharmacy",
+            LocationKind::Hospital => "hospital",
+            LocationKind::PoliceStation => "police_station",
+            LocationKind::HardwareStore => "hardware_store"
+        }
+    }
+}
+
+impl fmt::Display for LocationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            LocationKind::Street => "Street",
+            LocationKind::Park => "Park",
+            LocationKind::Apartment => "Apartment",
+            LocationKind::Grocery => "Grocery",
+            LocationKind::Pharmacy => "Pharmacy",
+            LocationKind::Hospital => "Hospital",
+            LocationKind::PoliceStation => "Police Station",
+            LocationKind::HardwareStore => "Hardware Store"
+        };
+
+        write!(f, "{kind}")
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Location {
+    pub id: LocationId,
+    pub name: String,
+    pub borough: String,
+    pub neighbourhood: String,
+    pub kind: LocationKind,
+    /// How dangerous the location is on its own, from 0 to 1
+    pub danger: f32,
+    /// Danger added by the hordes currently in the location
+    pub horde_danger: f32,
+    /// How much is left to scavenge, from 0 (picked clean) to 1
+    pub richness: f32,
+    /// The rooms inside, once somebody has gone in
+    pub interior: Option<Interior>,
+}
+
+impl Location {
+    /// Richness lost every time the location is scavenged
+    pub const RICHNESS_PER_SCAVENGE: f32 = 0.1;
+
+    /// How dangerous the location is right now, hordes included
+    pub fn get_danger(&self) -> f32 {
+        (self.danger + self.horde_danger).min(1.0)
+    }
+
+    /// Whether there is anything to go inside of
+    pub fn has_interior(&self) -> bool {
+        !matches!(self.kind, LocationKind::Street | LocationKind::Park)
+    }
+
+    pub fn get_danger_formatted(&self) -> &'static str {
+        match self.get_danger() {
+            d if d < 0.25 => "Safe",
+            d if d < 0.5 => "Risky",
+            d if d < 0.75 => "Dangerous",
+            _ => "Deadly"
+        }
+    }
+
+    pub fn get_richness_formatted(&self) -> &'static str {
+        match self.richness {
+            r if r < 0.2 => "Picked clean",
+            r if r < 0.5 => "Sparse",
+            r if r < 0.8 => "Decent",
+            _ => "Plentiful"
+        }
+    }
+
+    pub fn get_formatted(&self) -> String {
+        format!(
+            "{} ({}) - {}, {}\nDanger: {} | Resources: {}",
+            self.name,
+            self.kind,
+            self.neighbourhood,
+            self.borough,
+            self.get_danger_formatted(),
+            self.get_richness_formatted()
+        )
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}, {})", self.name, self.neighbourhood, self.borough)
+    }
+}
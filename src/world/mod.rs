@@ -0,0 +1,104 @@
+use serde::{Serialize, Deserialize};
+use std::{collections::BTreeMap, time::Duration};
+
+use crate::rng::GameRng;
+
+mod generation;
+mod horde;
+mod interior;
+mod location;
+
+pub use horde::{Horde, HordeMovement};
+pub use interior::{Interior, Room, RoomKind};
+pub use location::{Location, LocationId, LocationKind};
+
+/// A two way path between two locations
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct Route {
+    pub from: LocationId,
+    pub to: LocationId,
+    /// Distance in kilometres
+    pub distance: f32,
+}
+
+/// The map of every location around the city and how they connect.
+/// Generated from the game seed, see `World::generate`
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct World {
+    /// The seed the world was generated from
+    pub seed: u64,
+    /// Where new survivors wake up
+    pub start: LocationId,
+    locations: Vec<Location>,
+    routes: Vec<Route>,
+    /// Groups of undead roaming between locations
+    hordes: Vec<Horde>,
+    /// How loud it has been at each location lately, which draws hordes in
+    noise: BTreeMap<LocationId, f32>,
+}
+
+impl World {
+    /// Walking speed in kilometres per in-game hour
+    pub const WALKING_SPEED: f32 = 4.0;
+    /// Energy spent per kilometre walked
+    pub const ENERGY_PER_KM: f32 = 0.02;
+    /// Chance of being ambushed on the way to a location at full danger
+    pub const TRAVEL_ZOMBIE_CHANCE: f32 = 0.35;
+    /// Mixed into the world seed to generate the interior of each building
+    const INTERIOR_SEED_SALT: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    /// How long it takes to walk a distance, slowed down by the given multiplier
+    pub fn get_travel_duration(distance: f32, multiplier: f32) -> Duration {
+        Duration::from_secs_f32(distance / Self::WALKING_SPEED * multiplier * 3600.0)
+    }
+
+    pub fn locations(&self) -> &[Location] {
+        &self.locations
+    }
+
+    pub fn get(&self, id: LocationId) -> Option<&Location> {
+        self.locations.get(id.0 as usize)
+    }
+
+    pub fn get_mut(&mut self, id: LocationId) -> Option<&mut Location> {
+        self.locations.get_mut(id.0 as usize)
+    }
+
+    /// The location with the given id. Panics on ids that were never part of this world
+    pub fn location(&self, id: LocationId) -> &Location {
+        self.get(id).expect("Location does not exist")
+    }
+
+    /// The interior of a building, generated from the world seed the first time it is needed
+    pub fn get_interior_mut(&mut self, id: LocationId) -> Option<&mut Interior> {
+        let seed = self.seed;
+        let location = self.locations.get_mut(id.0 as usize)?;
+
+        if !location.has_interior() {
+            return None;
+        }
+
+        if location.interior.is_none() {
+            let rng = &mut GameRng::new(seed ^ (u64::from(id.0) + 1).wrapping_mul(Self::INTERIOR_SEED_SALT));
+            location.interior = Some(Interior::generate(rng, location));
+        }
+
+        location.interior.as_mut()
+    }
+
+    /// Every location reachable directly from the given one, with its distance
+    pub fn get_neighbours(&self, id: LocationId) -> Vec<(LocationId, f32)> {
+        self.routes
+            .iter()
+            .filter_map(|route| {
+                if route.from == id {
+                    Some((route.to, route.distance))
+                } else if route.to == id {
+                    Some((route.from, route.distance))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}